@@ -0,0 +1,184 @@
+use std::{
+    io::Cursor,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Router,
+};
+use image::ImageOutputFormat;
+use rive_rs::{vello::TextureRenderer, File, Handle};
+use serde::Deserialize;
+use tokio::sync::Semaphore;
+use wgpu::{Device, Queue};
+
+/// Largest `.riv` file accepted.
+const MAX_FILE_SIZE: usize = 16 * 1024 * 1024;
+/// Largest width or height of a thumbnail.
+const MAX_SIZE: u32 = 2048;
+/// Longest a request may take, including waiting for a free worker.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Number of thumbnails rendered concurrently.
+const WORKERS: usize = 4;
+
+#[derive(Debug, Deserialize)]
+struct Params {
+    #[serde(default = "default_size")]
+    width: u32,
+    #[serde(default = "default_size")]
+    height: u32,
+    /// Time to advance the scene by before rendering, in seconds.
+    #[serde(default)]
+    time: f32,
+}
+
+fn default_size() -> u32 {
+    256
+}
+
+/// Headless renderers shared between requests.
+///
+/// Creating a [`TextureRenderer`] compiles Vello's shaders, so they are kept around and reused.
+/// The semaphore bounds the number of renders in flight, which bounds the number of renderers.
+struct Pool {
+    device: Device,
+    queue: Queue,
+    renderers: Mutex<Vec<TextureRenderer>>,
+    permits: Arc<Semaphore>,
+}
+
+impl Pool {
+    fn render(&self, data: &[u8], params: &Params) -> Result<Vec<u8>, Response> {
+        let unprocessable =
+            |message: &str| (StatusCode::UNPROCESSABLE_ENTITY, message.to_owned()).into_response();
+        let internal_error = |error: vello::Error| {
+            (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+        };
+
+        // Reject what the runtime can't load before handing the bytes to it.
+        let report = File::validate(data).map_err(|error| unprocessable(&error.to_string()))?;
+        if !report.unknown_types.is_empty() {
+            return Err(unprocessable("file contains unknown object types"));
+        }
+
+        let file = File::new(data).map_err(|error| unprocessable(&error.to_string()))?;
+
+        // Negative, NaN, and huge times would panic in `Duration::from_secs_f32`.
+        let time = if params.time.is_nan() {
+            0.0
+        } else {
+            params.time.clamp(0.0, 3600.0)
+        };
+        let size = (
+            params.width.clamp(1, MAX_SIZE),
+            params.height.clamp(1, MAX_SIZE),
+        );
+
+        let mut renderer = match self.renderers.lock().unwrap().pop() {
+            Some(renderer) => renderer,
+            None => TextureRenderer::new(&self.device, &self.queue).map_err(internal_error)?,
+        };
+        let image = file.thumbnail(
+            &mut renderer,
+            &self.device,
+            &self.queue,
+            Handle::Default,
+            size,
+            Duration::from_secs_f32(time),
+        );
+        self.renderers.lock().unwrap().push(renderer);
+
+        let mut png = Vec::new();
+        image
+            .map_err(internal_error)?
+            .ok_or_else(|| unprocessable("file has no artboards"))?
+            .write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png)
+            .map_err(|error| internal_error(error.into()))?;
+
+        Ok(png)
+    }
+}
+
+async fn thumbnail(
+    State(pool): State<Arc<Pool>>,
+    Query(params): Query<Params>,
+    data: Bytes,
+) -> Response {
+    let render = async {
+        let permit = pool
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("the semaphore is never closed");
+        let pool = pool.clone();
+
+        // Rendering blocks, so it runs on the blocking thread pool. A render that exceeds the
+        // timeout can't be interrupted, but it keeps its permit until it's done, so runaway
+        // files can't pile up more work than there are workers.
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            pool.render(&data, &params)
+        })
+        .await
+    };
+
+    match tokio::time::timeout(REQUEST_TIMEOUT, render).await {
+        Ok(Ok(Ok(png))) => ([(header::CONTENT_TYPE, "image/png")], png).into_response(),
+        Ok(Ok(Err(response))) => response,
+        // The render panicked.
+        Ok(Err(_)) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        Err(_) => StatusCode::GATEWAY_TIMEOUT.into_response(),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            compatible_surface: None,
+            force_fallback_adapter: false,
+        })
+        .await
+        .expect("failed to find a GPU adapter");
+    let (device, queue) = adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("rive-rs server"),
+                features: wgpu::Features::empty(),
+                limits: wgpu::Limits::default(),
+            },
+            None,
+        )
+        .await
+        .expect("failed to create a GPU device");
+
+    let pool = Arc::new(Pool {
+        device,
+        queue,
+        renderers: Mutex::new(Vec::new()),
+        permits: Arc::new(Semaphore::new(WORKERS)),
+    });
+
+    let app = Router::new()
+        .route("/thumbnail", post(thumbnail))
+        .layer(DefaultBodyLimit::max(MAX_FILE_SIZE))
+        .with_state(pool);
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
+    println!("listening on http://{addr}");
+
+    axum::Server::bind(&addr)
+        .serve(app.into_make_service())
+        .await
+        .unwrap();
+}
@@ -0,0 +1,87 @@
+use std::env;
+
+use vello::{
+    kurbo::{Affine, BezPath, Rect, Shape},
+    peniko::{Color, Fill},
+    SceneBuilder,
+};
+
+/// Environment variable holding the custom background color as `RRGGBB` hex.
+const CUSTOM_COLOR_VAR: &str = "RIVE_VIEWER_BACKGROUND";
+
+const CHECKER_SIZE: f64 = 16.0;
+const CHECKER_DARK_COLOR: Color = Color::rgb8(153, 153, 153);
+const CHECKER_LIGHT_COLOR: Color = Color::rgb8(204, 204, 204);
+
+fn custom_color() -> Option<Color> {
+    let hex = env::var(CUSTOM_COLOR_VAR).ok()?;
+    let rgb = u32::from_str_radix(hex.trim_start_matches('#'), 16).ok()?;
+
+    Some(Color::rgb8((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+}
+
+/// What is drawn behind the scenes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Background {
+    #[default]
+    DimGray,
+    Dark,
+    Light,
+    /// The color set in the `RIVE_VIEWER_BACKGROUND` environment variable, if any.
+    Custom,
+    /// A checkerboard, drawn only behind translucent scenes.
+    Checkerboard,
+}
+
+impl Background {
+    /// Returns the next background in the cycle, skipping the custom color if none is set.
+    pub fn next(self) -> Self {
+        match self {
+            Background::DimGray => Background::Dark,
+            Background::Dark => Background::Light,
+            Background::Light if custom_color().is_some() => Background::Custom,
+            Background::Light | Background::Custom => Background::Checkerboard,
+            Background::Checkerboard => Background::DimGray,
+        }
+    }
+
+    /// Returns the color the frame is cleared with.
+    pub fn color(self) -> Color {
+        match self {
+            Background::DimGray => Color::DIM_GRAY,
+            Background::Dark => Color::rgb8(30, 30, 30),
+            Background::Light => Color::rgb8(245, 245, 245),
+            Background::Custom => custom_color().unwrap_or(Color::DIM_GRAY),
+            Background::Checkerboard => CHECKER_DARK_COLOR,
+        }
+    }
+
+    /// Draws the checkerboard over a `width` by `height` frame if it is enabled and the scene is
+    /// translucent.
+    pub fn draw(self, builder: &mut SceneBuilder, width: u32, height: u32, is_translucent: bool) {
+        if self != Background::Checkerboard || !is_translucent {
+            return;
+        }
+
+        let columns = (width as f64 / CHECKER_SIZE).ceil() as u32;
+        let rows = (height as f64 / CHECKER_SIZE).ceil() as u32;
+
+        let mut path = BezPath::new();
+        for row in 0..rows {
+            for column in (row % 2..columns).step_by(2) {
+                let x = column as f64 * CHECKER_SIZE;
+                let y = row as f64 * CHECKER_SIZE;
+
+                path.extend(Rect::new(x, y, x + CHECKER_SIZE, y + CHECKER_SIZE).path_elements(0.1));
+            }
+        }
+
+        builder.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            CHECKER_LIGHT_COLOR,
+            None,
+            &path,
+        );
+    }
+}
@@ -1,6 +1,11 @@
-use std::{fs, time::Duration, time::Instant};
+use std::{fs, path::Path, time::Duration, time::Instant};
 
-use rive_rs::{Artboard, File, Handle, Instantiate, Viewport};
+use rive_rs::{
+    clock::{Clock, SystemClock},
+    debug,
+    vello::{EncodingCache, LastFrameProfile},
+    Artboard, Cursor, File, Handle, Instantiate, Viewport,
+};
 use vello::{
     kurbo::{Affine, Rect, Vec2},
     peniko::{Color, Fill},
@@ -8,24 +13,98 @@ use vello::{
     Renderer, RendererOptions, Scene, SceneBuilder,
 };
 use winit::{
-    dpi::LogicalSize,
+    dpi::{LogicalSize, PhysicalSize},
     event::{ElementState, Event, KeyboardInput, MouseButton, VirtualKeyCode, WindowEvent},
     event_loop::{ControlFlow, EventLoop},
-    window::{Window, WindowBuilder},
+    window::{CursorIcon, Window, WindowBuilder},
 };
 
+mod background;
+mod overlay;
+mod scrubber;
+
+use background::Background;
+use overlay::{Overlay, Stage};
+use scrubber::Scrubber;
+
 struct RenderState {
     surface: RenderSurface,
     window: Window,
 }
 
 const INITIAL_WINDOW_SIZE: LogicalSize<u32> = LogicalSize::new(700, 700);
-const FRAME_STATS_CAPACITY: usize = 30;
 const SCROLL_FACTOR_THRESHOLD: f64 = 100.0;
 
+fn load_scene(path: &Path) -> Box<dyn rive_rs::Scene> {
+    let file = File::new(&fs::read(path).unwrap()).unwrap();
+    let artboard = Artboard::instantiate(&file, Handle::Default).unwrap();
+
+    Box::<dyn rive_rs::Scene>::instantiate(&artboard, Handle::Default)
+        .unwrap_or_else(|| Box::new(artboard) as Box<dyn rive_rs::Scene>)
+}
+
+/// Resizes `viewport` to cover the window or, when comparing, half of it.
+fn resize_viewport(viewport: &mut Viewport, window_size: PhysicalSize<u32>, is_comparing: bool) {
+    let width = if is_comparing {
+        window_size.width / 2
+    } else {
+        window_size.width
+    };
+
+    viewport.resize(width, window_size.height);
+}
+
+/// Maps a window position to a position relative to the viewport under it. When comparing, both
+/// halves of the window map to the same positions, so that both scenes receive the same input.
+fn local_pos(pos: Vec2, is_comparing: bool, window_width: u32) -> [f32; 2] {
+    let half_width = (window_width / 2) as f64;
+
+    if is_comparing && pos.x >= half_width {
+        [(pos.x - half_width) as f32, pos.y as f32]
+    } else {
+        [pos.x as f32, pos.y as f32]
+    }
+}
+
+/// Advances `scene` by `elapsed` and draws it translated by `offset`, adding the time spent in
+/// each stage to `advance_time` and `encode_time`.
+#[allow(clippy::too_many_arguments)]
+fn advance_and_draw(
+    scene: &mut dyn rive_rs::Scene,
+    renderer: &mut rive_rs::Renderer,
+    viewport: &mut Viewport,
+    elapsed: Duration,
+    offset: Vec2,
+    debug_layers: debug::Layers,
+    advance_time: &mut Duration,
+    encode_time: &mut Duration,
+) {
+    use rive_rs::renderer::Renderer as _;
+
+    let advance_start = Instant::now();
+    scene.advance_and_apply(elapsed);
+    *advance_time += advance_start.elapsed();
+
+    let encode_start = Instant::now();
+    renderer.transform(&[1.0, 0.0, 0.0, 1.0, offset.x as f32, offset.y as f32]);
+    scene.draw_in(renderer, viewport);
+    debug::draw_overlay(&*scene, renderer, viewport, debug_layers);
+    renderer.state_pop();
+    *encode_time += encode_start.elapsed();
+}
+
 fn main() {
     let mut viewport = Viewport::default();
-    let mut scene: Option<Box<dyn rive_rs::Scene>> = None;
+    // Every dropped file opens in a new tab. Inactive tabs keep their state but are not advanced.
+    let mut tabs: Vec<Box<dyn rive_rs::Scene>> = Vec::new();
+    let mut active_tab = 0;
+
+    // Side-by-side comparison: the compared scene is drawn in the right half of the window with
+    // the same elapsed time and pointer events as the active tab.
+    let mut is_comparing = false;
+    let mut compared_viewport = Viewport::default();
+    let mut compared_scene: Option<Box<dyn rive_rs::Scene>> = None;
+    let mut window_size = INITIAL_WINDOW_SIZE.to_physical::<u32>(1.0);
 
     let event_loop = EventLoop::new();
     let mut cached_window: Option<Window> = None;
@@ -35,8 +114,18 @@ fn main() {
 
     let mut mouse_pos = Vec2::default();
     let mut scroll_delta = 0.0;
-    let mut frame_start_time = Instant::now();
-    let mut stats = Vec::with_capacity(FRAME_STATS_CAPACITY);
+    let mut clock = SystemClock::new();
+    let mut overlay = Overlay::default();
+    let mut scrubber = Scrubber::default();
+    // Kept across frames so that their buffers are reused. Drawables that didn't change since
+    // the last frame are appended from the encoding cache.
+    let mut rive_renderer = rive_rs::Renderer::with_encoding_cache(EncodingCache::default());
+    let mut instance = rive_rs::Renderer::default();
+    let mut show_overlay = true;
+    let mut debug_layers = debug::Layers::empty();
+    let mut is_instanced = false;
+    let mut background = Background::default();
+    let mut title = String::new();
 
     let mut h = 0;
     let mut j = 0;
@@ -51,7 +140,11 @@ fn main() {
             match event {
                 WindowEvent::CloseRequested => *control_flow = ControlFlow::Exit,
                 WindowEvent::Resized(size) => {
-                    viewport.resize(size.width, size.height);
+                    window_size = *size;
+
+                    for viewport in [&mut viewport, &mut compared_viewport] {
+                        resize_viewport(viewport, *size, is_comparing);
+                    }
 
                     render_cx.resize_surface(&mut render_state.surface, size.width, size.height);
                     render_state.window.request_redraw();
@@ -61,24 +154,76 @@ fn main() {
                     button: MouseButton::Left,
                     ..
                 } => {
-                    if let Some(scene) = &mut scene {
-                        match state {
-                            ElementState::Pressed => scene.pointer_down(
-                                mouse_pos.x as f32,
-                                mouse_pos.y as f32,
-                                &viewport,
-                            ),
-                            ElementState::Released => {
-                                scene.pointer_up(mouse_pos.x as f32, mouse_pos.y as f32, &viewport)
+                    let is_scrubbing = match state {
+                        ElementState::Pressed => tabs.get_mut(active_tab).is_some_and(|scene| {
+                            scrubber.press(
+                                scene.as_mut(),
+                                mouse_pos,
+                                window_size.width,
+                                window_size.height,
+                            )
+                        }),
+                        ElementState::Released => scrubber.release(),
+                    };
+
+                    if is_scrubbing {
+                        return;
+                    }
+
+                    let [x, y] = local_pos(mouse_pos, is_comparing, window_size.width);
+                    let scenes = [
+                        (tabs.get_mut(active_tab), &viewport),
+                        (compared_scene.as_mut(), &compared_viewport),
+                    ];
+
+                    for (scene, viewport) in scenes {
+                        if let Some(scene) = scene {
+                            match state {
+                                ElementState::Pressed => scene.pointer_down(x, y, viewport),
+                                ElementState::Released => scene.pointer_up(x, y, viewport),
                             }
                         }
                     }
                 }
                 WindowEvent::CursorMoved { position, .. } => {
                     mouse_pos = Vec2::new(position.x, position.y);
-                    if let Some(scene) = &mut scene {
-                        scene.pointer_move(mouse_pos.x as f32, mouse_pos.y as f32, &viewport);
+
+                    if let Some(scene) = tabs.get_mut(active_tab) {
+                        if scrubber.drag(
+                            scene.as_mut(),
+                            mouse_pos,
+                            window_size.width,
+                            window_size.height,
+                        ) {
+                            return;
+                        }
+                    }
+
+                    let [x, y] = local_pos(mouse_pos, is_comparing, window_size.width);
+                    let scenes = [
+                        (tabs.get_mut(active_tab), &viewport),
+                        (compared_scene.as_mut(), &compared_viewport),
+                    ];
+
+                    for (scene, viewport) in scenes {
+                        if let Some(scene) = scene {
+                            scene.pointer_move(x, y, viewport);
+                        }
                     }
+
+                    let hovered = if is_comparing && mouse_pos.x >= (window_size.width / 2) as f64 {
+                        compared_scene
+                            .as_ref()
+                            .map(|scene| scene.cursor(x, y, &compared_viewport))
+                    } else {
+                        tabs.get(active_tab)
+                            .map(|scene| scene.cursor(x, y, &viewport))
+                    };
+
+                    render_state.window.set_cursor_icon(match hovered {
+                        Some(Cursor::Pointer) => CursorIcon::Hand,
+                        _ => CursorIcon::Default,
+                    });
                 }
                 WindowEvent::MouseWheel { delta, .. } => match delta {
                     winit::event::MouseScrollDelta::LineDelta(_, lines_y) => {
@@ -91,13 +236,12 @@ fn main() {
                     }
                 },
                 WindowEvent::DroppedFile(path) => {
-                    scene = Some({
-                        let file = File::new(&fs::read(path).unwrap()).unwrap();
-                        let artboard = Artboard::instantiate(&file, Handle::Default).unwrap();
-
-                        Box::<dyn rive_rs::Scene>::instantiate(&artboard, Handle::Default)
-                            .unwrap_or_else(|| Box::new(artboard) as Box<dyn rive_rs::Scene>)
-                    });
+                    if is_comparing && !tabs.is_empty() {
+                        compared_scene = Some(load_scene(path));
+                    } else {
+                        tabs.push(load_scene(path));
+                        active_tab = tabs.len() - 1;
+                    }
                 }
                 WindowEvent::KeyboardInput {
                     input:
@@ -111,6 +255,36 @@ fn main() {
                     Some(VirtualKeyCode::H) => h += 1,
                     Some(VirtualKeyCode::J) => j += 1,
                     Some(VirtualKeyCode::K) => k += 1,
+                    Some(VirtualKeyCode::O) => show_overlay = !show_overlay,
+                    Some(VirtualKeyCode::D) => debug_layers.toggle(debug::Layers::all()),
+                    Some(VirtualKeyCode::I) => is_instanced = !is_instanced,
+                    Some(VirtualKeyCode::B) => background = background.next(),
+                    Some(VirtualKeyCode::Tab) if !tabs.is_empty() => {
+                        active_tab = (active_tab + 1) % tabs.len();
+                    }
+                    Some(
+                        key @ (VirtualKeyCode::Key1
+                        | VirtualKeyCode::Key2
+                        | VirtualKeyCode::Key3
+                        | VirtualKeyCode::Key4
+                        | VirtualKeyCode::Key5
+                        | VirtualKeyCode::Key6
+                        | VirtualKeyCode::Key7
+                        | VirtualKeyCode::Key8
+                        | VirtualKeyCode::Key9),
+                    ) => {
+                        let index = key as usize - VirtualKeyCode::Key1 as usize;
+                        if index < tabs.len() {
+                            active_tab = index;
+                        }
+                    }
+                    Some(VirtualKeyCode::C) => {
+                        is_comparing = !is_comparing;
+
+                        for viewport in [&mut viewport, &mut compared_viewport] {
+                            resize_viewport(viewport, window_size, is_comparing);
+                        }
+                    }
                     _ => (),
                 },
                 _ => {}
@@ -122,27 +296,32 @@ fn main() {
             }
         }
         Event::RedrawRequested(_) => {
-            let mut rive_renderer = rive_rs::Renderer::default();
+            rive_renderer.reset();
 
-            let elapsed = &frame_start_time.elapsed();
-            stats.push(elapsed.as_secs_f64());
+            let ticked = clock.tick();
+            // Hold the frame the scrubber seeked to for as long as it's being dragged.
+            let elapsed = &if scrubber.is_dragging() {
+                Duration::ZERO
+            } else {
+                ticked
+            };
 
-            if stats.len() == FRAME_STATS_CAPACITY {
-                let average = stats.drain(..).sum::<f64>() / FRAME_STATS_CAPACITY as f64;
+            let copies = (1 + h * 2) * (1 + k + j);
+            let mut new_title = format!("Rive on Vello demo ({} copies)", copies);
+            if let Some((frame, frame_count)) = tabs
+                .get(active_tab)
+                .and_then(|scene| Scrubber::frame(scene.as_ref()))
+            {
+                new_title += &format!(" - frame {}/{}", frame, frame_count - 1);
+            }
 
+            if new_title != title {
                 if let Some(state) = &mut render_state {
-                    let copies = (h > 0 || j > 0 || k > 0)
-                        .then(|| format!(" ({} copies)", (1 + h * 2) * (1 + k + j)))
-                        .unwrap_or_default();
-                    state.window.set_title(&format!(
-                        "Rive on Vello demo | {:.2}ms{}",
-                        average * 1000.0,
-                        copies
-                    ));
+                    state.window.set_title(&new_title);
                 }
-            }
 
-            frame_start_time = Instant::now();
+                title = new_title;
+            }
 
             let Some(render_state) = &mut render_state else {
                 return;
@@ -152,7 +331,7 @@ fn main() {
             let device_handle = &render_cx.devices[render_state.surface.dev_id];
 
             let render_params = vello::RenderParams {
-                base_color: Color::DIM_GRAY,
+                base_color: background.color(),
                 width,
                 height,
                 antialiasing_method: vello::AaConfig::Area,
@@ -166,10 +345,78 @@ fn main() {
 
             let mut vello_scene = Scene::default();
             let mut builder = SceneBuilder::for_scene(&mut vello_scene);
+
+            let is_translucent = [
+                tabs.get(active_tab),
+                compared_scene.as_ref().filter(|_| is_comparing),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|scene| scene.is_translucent());
+            background.draw(&mut builder, width, height, is_translucent);
+
             let spacing = 200;
             let instances = ((1 + h * 2) * (1 + k + j)) as f64;
 
-            if let Some(scene) = &mut scene {
+            let mut advance_time = Duration::ZERO;
+            let mut encode_time = Duration::ZERO;
+
+            if is_comparing {
+                let scenes = [
+                    (tabs.get_mut(active_tab), &mut viewport, Vec2::ZERO),
+                    (
+                        compared_scene.as_mut(),
+                        &mut compared_viewport,
+                        Vec2::new((width / 2) as f64, 0.0),
+                    ),
+                ];
+
+                for (scene, viewport, offset) in scenes {
+                    if let Some(scene) = scene {
+                        advance_and_draw(
+                            scene.as_mut(),
+                            &mut rive_renderer,
+                            viewport,
+                            *elapsed,
+                            offset,
+                            debug_layers,
+                            &mut advance_time,
+                            &mut encode_time,
+                        );
+                    }
+                }
+            } else if let Some(scene) = tabs.get_mut(active_tab).filter(|_| is_instanced) {
+                // Draw the scene once and instance it, so all copies show the same frame.
+                instance.reset();
+                advance_and_draw(
+                    scene.as_mut(),
+                    &mut instance,
+                    &mut viewport,
+                    *elapsed,
+                    Vec2::ZERO,
+                    debug_layers,
+                    &mut advance_time,
+                    &mut encode_time,
+                );
+
+                let encode_start = Instant::now();
+                rive_renderer.draw_instances(
+                    &instance,
+                    (0..(k + 1 + j)).flat_map(|j| {
+                        (0..(h * 2 + 1)).map(move |i| {
+                            [
+                                1.0,
+                                0.0,
+                                0.0,
+                                1.0,
+                                ((i - h) * spacing) as f32,
+                                ((j - k) * spacing) as f32,
+                            ]
+                        })
+                    }),
+                );
+                encode_time += encode_start.elapsed();
+            } else if let Some(scene) = tabs.get_mut(active_tab) {
                 let advance_per_instance = scene
                     .duration()
                     .map(|d| Duration::from_secs_f64(d.as_secs_f64() / instances * 797.0))
@@ -177,25 +424,32 @@ fn main() {
 
                 for j in 0..(k + 1 + j) {
                     for i in 0..(h * 2 + 1) {
-                        use rive_rs::renderer::Renderer as _;
-                        rive_renderer.transform(&[
-                            1.0,
-                            0.0,
-                            0.0,
-                            1.0,
-                            ((i - h) * spacing) as f32,
-                            ((j - k) * spacing) as f32,
-                        ]);
                         let mut advance = advance_per_instance;
                         if j == 0 && i == 0 {
                             advance += *elapsed;
                         }
-                        scene.advance_and_maybe_draw(&mut rive_renderer, advance, &mut viewport);
-                        rive_renderer.state_pop();
+
+                        advance_and_draw(
+                            scene.as_mut(),
+                            &mut rive_renderer,
+                            &mut viewport,
+                            advance,
+                            Vec2::new(((i - h) * spacing) as f64, ((j - k) * spacing) as f64),
+                            debug_layers,
+                            &mut advance_time,
+                            &mut encode_time,
+                        );
                     }
                 }
+            }
 
+            if !tabs.is_empty() {
+                let encode_start = Instant::now();
                 builder.append(rive_renderer.scene(), Some(Affine::default()));
+                encode_time += encode_start.elapsed();
+
+                overlay.record(Stage::Advance, advance_time);
+                overlay.record(Stage::Encode, encode_time);
             } else {
                 // Vello doesn't draw base color when there is no geometry.
                 builder.fill(
@@ -207,17 +461,20 @@ fn main() {
                 );
             }
 
-            // if let Some(profiling_result) = renderer
-            //     .as_mut()
-            //     .and_then(|it| it.profile_result.take())
-            // {
-            //     if !profiling_result.is_empty() {
-            //         let start = profiling_result.first().unwrap().time.start;
-            //         let end = profiling_result.last().unwrap().time.end;
-
-            //         dbg!((end - start) * 1000.0);
-            //     }
-            // }
+            if show_overlay {
+                overlay.draw(&mut builder);
+            }
+
+            if let Some(scene) = tabs.get(active_tab) {
+                scrubber.draw(&mut builder, scene.as_ref(), width, height);
+            }
+
+            if let Some(profile) = renderer
+                .as_mut()
+                .and_then(|it| it.take_last_frame_profile())
+            {
+                overlay.record(Stage::Gpu, profile.total());
+            }
 
             vello::block_on_wgpu(
                 &device_handle.device,
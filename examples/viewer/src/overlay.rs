@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use vello::{
+    kurbo::{Affine, Rect},
+    peniko::{Color, Fill},
+    SceneBuilder,
+};
+
+/// Number of frames over which every stage timing is averaged.
+const FRAME_STATS_CAPACITY: usize = 30;
+
+const PADDING: f64 = 8.0;
+const BAR_WIDTH: f64 = 240.0;
+const BAR_HEIGHT: f64 = 10.0;
+const BAR_SPACING: f64 = 4.0;
+/// Time represented by the full width of a bar; one frame at 60Hz.
+const BAR_BUDGET: Duration = Duration::from_micros(16_667);
+
+const BACKGROUND_COLOR: Color = Color::rgba8(0, 0, 0, 160);
+const TRACK_COLOR: Color = Color::rgba8(255, 255, 255, 40);
+const TICK_COLOR: Color = Color::rgba8(255, 255, 255, 120);
+const OVER_BUDGET_COLOR: Color = Color::rgba8(255, 64, 64, 255);
+
+#[derive(Clone, Copy, Debug)]
+pub enum Stage {
+    /// Time spent advancing scenes on the CPU.
+    Advance,
+    /// Time spent encoding the Vello scene on the CPU.
+    Encode,
+    /// Time spent by the GPU rendering the frame, read from timestamp queries.
+    Gpu,
+}
+
+impl Stage {
+    const ALL: [Stage; 3] = [Stage::Advance, Stage::Encode, Stage::Gpu];
+
+    fn color(self) -> Color {
+        match self {
+            Stage::Advance => Color::rgb8(102, 204, 255),
+            Stage::Encode => Color::rgb8(255, 204, 102),
+            Stage::Gpu => Color::rgb8(153, 255, 102),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct StageStats {
+    samples: Vec<Duration>,
+    average: Option<Duration>,
+}
+
+impl StageStats {
+    fn push(&mut self, sample: Duration) {
+        self.samples.push(sample);
+
+        if self.samples.len() == FRAME_STATS_CAPACITY {
+            let sum: Duration = self.samples.drain(..).sum();
+            self.average = Some(sum / FRAME_STATS_CAPACITY as u32);
+        }
+    }
+}
+
+/// On-screen overlay showing a per-stage breakdown of the frame time.
+///
+/// Every stage is drawn as a bar whose full width represents [`BAR_BUDGET`], with a tick every
+/// quarter. Bars that exceed the budget are drawn in red.
+#[derive(Debug, Default)]
+pub struct Overlay {
+    stages: [StageStats; 3],
+}
+
+impl Overlay {
+    pub fn record(&mut self, stage: Stage, sample: Duration) {
+        self.stages[stage as usize].push(sample);
+    }
+
+    pub fn average(&self, stage: Stage) -> Option<Duration> {
+        self.stages[stage as usize].average
+    }
+
+    pub fn draw(&self, builder: &mut SceneBuilder) {
+        let height =
+            PADDING * 2.0 + Stage::ALL.len() as f64 * (BAR_HEIGHT + BAR_SPACING) - BAR_SPACING;
+
+        builder.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            BACKGROUND_COLOR,
+            None,
+            &Rect::new(0.0, 0.0, BAR_WIDTH + PADDING * 2.0, height),
+        );
+
+        for (i, stage) in Stage::ALL.into_iter().enumerate() {
+            let y = PADDING + i as f64 * (BAR_HEIGHT + BAR_SPACING);
+
+            builder.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                TRACK_COLOR,
+                None,
+                &Rect::new(PADDING, y, PADDING + BAR_WIDTH, y + BAR_HEIGHT),
+            );
+
+            if let Some(average) = self.average(stage) {
+                let ratio = average.as_secs_f64() / BAR_BUDGET.as_secs_f64();
+                let color = if ratio > 1.0 {
+                    OVER_BUDGET_COLOR
+                } else {
+                    stage.color()
+                };
+
+                builder.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    color,
+                    None,
+                    &Rect::new(
+                        PADDING,
+                        y,
+                        PADDING + BAR_WIDTH * ratio.min(1.0),
+                        y + BAR_HEIGHT,
+                    ),
+                );
+            }
+
+            for tick in 1..4 {
+                let x = PADDING + BAR_WIDTH * tick as f64 / 4.0;
+
+                builder.fill(
+                    Fill::NonZero,
+                    Affine::IDENTITY,
+                    TICK_COLOR,
+                    None,
+                    &Rect::new(x, y, x + 1.0, y + BAR_HEIGHT),
+                );
+            }
+        }
+    }
+}
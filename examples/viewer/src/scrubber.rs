@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use rive_rs::Scene;
+use vello::{
+    kurbo::{Affine, Rect, RoundedRect, Vec2},
+    peniko::{Color, Fill},
+    SceneBuilder,
+};
+
+const PADDING: f64 = 8.0;
+const BAR_HEIGHT: f64 = 6.0;
+/// Height of the area around the bar that reacts to the pointer.
+const HIT_HEIGHT: f64 = BAR_HEIGHT + PADDING * 2.0;
+const HANDLE_RADIUS: f64 = 7.0;
+
+const TRACK_COLOR: Color = Color::rgba8(255, 255, 255, 60);
+const PROGRESS_COLOR: Color = Color::rgba8(255, 255, 255, 200);
+const HANDLE_COLOR: Color = Color::rgb8(255, 255, 255);
+const DRAGGING_HANDLE_COLOR: Color = Color::rgb8(102, 204, 255);
+
+/// The frames of a linear animation's timeline, limited to its work area.
+#[derive(Clone, Copy, Debug)]
+struct Timeline {
+    fps: u32,
+    start_frame: u32,
+    frame_count: u32,
+}
+
+impl Timeline {
+    fn of(scene: &dyn Scene) -> Option<Self> {
+        let animation = scene.as_linear_animation()?;
+        let fps = scene.fps()?.max(1);
+        let frame_count = scene.frame_count()?.max(1);
+        let start_frame = animation
+            .work_area()
+            .map(|frames| frames.start)
+            .unwrap_or_default();
+
+        Some(Self {
+            fps,
+            start_frame,
+            frame_count,
+        })
+    }
+
+    /// Returns the frame at `time`, relative to the start of the work area.
+    fn frame_at(&self, time: Duration) -> u32 {
+        let frame = (time.as_secs_f64() * self.fps as f64).round() as u32;
+        frame
+            .saturating_sub(self.start_frame)
+            .min(self.frame_count - 1)
+    }
+
+    fn time_of(&self, frame: u32) -> Duration {
+        Duration::from_secs_f64((self.start_frame + frame) as f64 / self.fps as f64)
+    }
+}
+
+fn track_rect(width: u32, height: u32) -> Rect {
+    let y = height as f64 - PADDING - BAR_HEIGHT;
+    Rect::new(PADDING, y, width as f64 - PADDING, y + BAR_HEIGHT)
+}
+
+/// Timeline scrubber drawn along the bottom of the window for scenes that are linear animations.
+///
+/// Pressing anywhere on the bar seeks to the frame under the pointer and dragging keeps seeking;
+/// the scene doesn't advance while the bar is held.
+#[derive(Debug, Default)]
+pub struct Scrubber {
+    is_dragging: bool,
+}
+
+impl Scrubber {
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    /// Returns the current frame of `scene` and its number of frames, or `None` if the scene is
+    /// not a linear animation.
+    pub fn frame(scene: &dyn Scene) -> Option<(u32, u32)> {
+        let timeline = Timeline::of(scene)?;
+        let time = scene.as_linear_animation()?.time();
+
+        Some((timeline.frame_at(time), timeline.frame_count))
+    }
+
+    fn seek(scene: &mut dyn Scene, pos: Vec2, width: u32, height: u32) {
+        let Some(timeline) = Timeline::of(scene) else {
+            return;
+        };
+
+        let track = track_rect(width, height);
+        let ratio = ((pos.x - track.x0) / track.width()).clamp(0.0, 1.0);
+        let frame = (ratio * (timeline.frame_count - 1) as f64).round() as u32;
+
+        if let Some(animation) = scene.as_linear_animation_mut() {
+            animation.set_time(timeline.time_of(frame));
+        }
+
+        scene.advance_and_apply(Duration::ZERO);
+    }
+
+    /// Starts dragging if `pos` is on the bar, returning whether the press was handled.
+    pub fn press(&mut self, scene: &mut dyn Scene, pos: Vec2, width: u32, height: u32) -> bool {
+        if Timeline::of(scene).is_none() || pos.y < height as f64 - HIT_HEIGHT {
+            return false;
+        }
+
+        self.is_dragging = true;
+        Self::seek(scene, pos, width, height);
+
+        true
+    }
+
+    /// Seeks while dragging, returning whether the move was handled.
+    pub fn drag(&mut self, scene: &mut dyn Scene, pos: Vec2, width: u32, height: u32) -> bool {
+        if self.is_dragging {
+            Self::seek(scene, pos, width, height);
+        }
+
+        self.is_dragging
+    }
+
+    /// Stops dragging, returning whether the release was handled.
+    pub fn release(&mut self) -> bool {
+        std::mem::take(&mut self.is_dragging)
+    }
+
+    pub fn draw(&self, builder: &mut SceneBuilder, scene: &dyn Scene, width: u32, height: u32) {
+        let Some((frame, frame_count)) = Self::frame(scene) else {
+            return;
+        };
+
+        let track = track_rect(width, height);
+        let ratio = frame as f64 / (frame_count - 1).max(1) as f64;
+        let x = track.x0 + track.width() * ratio;
+
+        builder.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            TRACK_COLOR,
+            None,
+            &RoundedRect::from_rect(track, BAR_HEIGHT / 2.0),
+        );
+        builder.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            PROGRESS_COLOR,
+            None,
+            &RoundedRect::from_rect(Rect { x1: x, ..track }, BAR_HEIGHT / 2.0),
+        );
+
+        let handle_color = if self.is_dragging {
+            DRAGGING_HANDLE_COLOR
+        } else {
+            HANDLE_COLOR
+        };
+        let center_y = track.center().y;
+
+        builder.fill(
+            Fill::NonZero,
+            Affine::IDENTITY,
+            handle_color,
+            None,
+            &Rect::new(
+                x - HANDLE_RADIUS,
+                center_y - HANDLE_RADIUS,
+                x + HANDLE_RADIUS,
+                center_y + HANDLE_RADIUS,
+            )
+            .to_rounded_rect(HANDLE_RADIUS),
+        );
+    }
+}
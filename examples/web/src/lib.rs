@@ -0,0 +1,184 @@
+// See the target-specific dependencies in `Cargo.toml`.
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use rive_rs::{canvas::Renderer, Artboard, Cursor, File, Handle, Instantiate, Scene, Viewport};
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    CanvasRenderingContext2d, HtmlCanvasElement, PointerEvent, Response, UrlSearchParams, Window,
+};
+
+/// File loaded when the page's URL has no `?file=` parameter.
+const DEFAULT_FILE: &str = "scene.riv";
+/// Longest time a single frame advances the scene by. `requestAnimationFrame` pauses in
+/// background tabs, and the scene should resume where it left off instead of jumping ahead.
+const MAX_ELAPSED: Duration = Duration::from_millis(100);
+
+struct App {
+    canvas: HtmlCanvasElement,
+    scene: Box<dyn Scene<Renderer>>,
+    renderer: Renderer,
+    viewport: Viewport,
+    /// Timestamp of the previous animation frame, in milliseconds.
+    last_time: Option<f64>,
+    /// Set when the scene needs to be drawn even if it doesn't advance, e.g. after a resize.
+    needs_redraw: bool,
+}
+
+impl App {
+    /// Matches the canvas' backing store to its CSS size and the device pixel ratio, so that the
+    /// scene is drawn sharply on high-DPI screens.
+    fn resize(&mut self) {
+        let scale = web_sys::window().map_or(1.0, |window| window.device_pixel_ratio()) as f32;
+        let width = self.canvas.client_width().max(0) as u32;
+        let height = self.canvas.client_height().max(0) as u32;
+
+        if width == self.viewport.width()
+            && height == self.viewport.height()
+            && scale == self.viewport.resolution_scale()
+        {
+            return;
+        }
+
+        self.canvas.set_width((width as f32 * scale).round() as u32);
+        self.canvas
+            .set_height((height as f32 * scale).round() as u32);
+
+        self.viewport.resize(width, height);
+        self.viewport.set_resolution_scale(scale);
+        self.needs_redraw = true;
+    }
+
+    fn frame(&mut self, time: f64) {
+        self.resize();
+
+        let elapsed = self.last_time.map_or(Duration::ZERO, |last_time| {
+            Duration::from_secs_f64(((time - last_time) / 1000.0).max(0.0)).min(MAX_ELAPSED)
+        });
+        self.last_time = Some(time);
+
+        let is_playing = self.scene.advance_and_apply(elapsed);
+
+        // The canvas keeps what was drawn into it, so settled scenes are left as they are. The
+        // frame after the scene settles is still drawn in order to show its final state.
+        if is_playing || self.needs_redraw {
+            self.renderer.clear();
+            self.scene.draw_in(&mut self.renderer, &mut self.viewport);
+        }
+        self.needs_redraw = is_playing;
+    }
+
+    fn update_cursor(&self, x: f32, y: f32) {
+        let cursor = match self.scene.cursor(x, y, &self.viewport) {
+            Cursor::Default => "default",
+            Cursor::Pointer => "pointer",
+        };
+
+        let _ = self.canvas.style().set_property("cursor", cursor);
+    }
+}
+
+async fn fetch(window: &Window, url: &str) -> Result<Vec<u8>, JsValue> {
+    let response: Response = JsFuture::from(window.fetch_with_str(url))
+        .await?
+        .dyn_into()?;
+
+    if !response.ok() {
+        return Err(format!("failed to fetch {url}: {}", response.status()).into());
+    }
+
+    let buffer = JsFuture::from(response.array_buffer()?).await?;
+
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// Forwards the canvas' pointer events of type `event` to `handler` with the pointer position
+/// in CSS pixels relative to the canvas.
+fn add_pointer_listener(
+    app: &Rc<RefCell<App>>,
+    event: &str,
+    handler: fn(&mut App, f32, f32),
+) -> Result<(), JsValue> {
+    let canvas = app.borrow().canvas.clone();
+    let app = app.clone();
+
+    let closure = Closure::<dyn FnMut(PointerEvent)>::new(move |event: PointerEvent| {
+        handler(
+            &mut app.borrow_mut(),
+            event.offset_x() as f32,
+            event.offset_y() as f32,
+        );
+    });
+
+    canvas.add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())?;
+    // The listener stays registered for as long as the page is open.
+    closure.forget();
+
+    Ok(())
+}
+
+fn request_animation_frame(callback: &Closure<dyn FnMut(f64)>) {
+    web_sys::window()
+        .expect("no global window")
+        .request_animation_frame(callback.as_ref().unchecked_ref())
+        .expect("failed to request an animation frame");
+}
+
+#[wasm_bindgen(start)]
+pub async fn start() -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("no global window")?;
+    let document = window.document().ok_or("no document")?;
+    let canvas: HtmlCanvasElement = document
+        .get_element_by_id("canvas")
+        .ok_or("no #canvas element")?
+        .dyn_into()?;
+    let context: CanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or("Canvas 2D is not supported")?
+        .dyn_into()?;
+
+    let params = UrlSearchParams::new_with_str(&window.location().search()?)?;
+    let url = params
+        .get("file")
+        .unwrap_or_else(|| DEFAULT_FILE.to_owned());
+
+    let file = File::<Renderer>::new(&fetch(&window, &url).await?)
+        .map_err(|error| format!("failed to load {url}: {error}"))?;
+    let artboard = Artboard::instantiate(&file, Handle::Default).ok_or("file has no artboards")?;
+    let scene = Box::<dyn Scene<Renderer>>::instantiate(&artboard, Handle::Default)
+        .unwrap_or_else(|| Box::new(artboard) as Box<dyn Scene<Renderer>>);
+
+    let app = Rc::new(RefCell::new(App {
+        canvas,
+        scene,
+        renderer: Renderer::new(context),
+        viewport: Viewport::default(),
+        last_time: None,
+        needs_redraw: true,
+    }));
+
+    add_pointer_listener(&app, "pointerdown", |app, x, y| {
+        app.scene.pointer_down(x, y, &app.viewport);
+    })?;
+    add_pointer_listener(&app, "pointermove", |app, x, y| {
+        app.scene.pointer_move(x, y, &app.viewport);
+        app.update_cursor(x, y);
+    })?;
+    add_pointer_listener(&app, "pointerup", |app, x, y| {
+        app.scene.pointer_up(x, y, &app.viewport);
+    })?;
+
+    // The callback requests the next frame itself, so it holds on to its own closure.
+    let callback: Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>> = Rc::new(RefCell::new(None));
+    let next = callback.clone();
+
+    *callback.borrow_mut() = Some(Closure::new(move |time: f64| {
+        app.borrow_mut().frame(time);
+        request_animation_frame(next.borrow().as_ref().expect("callback is set"));
+    }));
+    request_animation_frame(callback.borrow().as_ref().expect("callback is set"));
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use std::{
     env,
     ffi::OsString,
+    fs,
     path::{Path, PathBuf},
+    process::Command,
 };
 
 use walkdir::WalkDir;
@@ -18,13 +20,52 @@ fn all_files_with_extension<P: AsRef<Path>>(
     })
 }
 
+fn git(path: &Path, args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_owned())
+}
+
+/// Finds the rive-cpp commit that is being built, either from its git checkout or from the
+/// `.rive_head` file that pins the submodule.
+///
+/// An empty submodule isn't a checkout of its own and git would resolve it to the superproject
+/// instead, so the checkout is only trusted if its top level is `rive_cpp_path` itself.
+fn rive_cpp_commit(rive_cpp_path: &Path) -> String {
+    let is_checkout = git(rive_cpp_path, &["rev-parse", "--show-toplevel"])
+        .and_then(|toplevel| fs::canonicalize(toplevel).ok())
+        .zip(fs::canonicalize(rive_cpp_path).ok())
+        .map_or(false, |(toplevel, path)| toplevel == path);
+
+    is_checkout
+        .then(|| git(rive_cpp_path, &["rev-parse", "HEAD"]))
+        .flatten()
+        .or_else(|| fs::read_to_string("../.rive_head").ok())
+        .map(|commit| commit.trim().to_owned())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
 fn main() {
     println!("cargo:rerun-if-changed=src/ffi.cpp");
+    println!("cargo:rerun-if-changed=../.rive_head");
+    println!("cargo:rerun-if-env-changed=RIVE_CPP_PATH");
 
     let rive_cpp_path = env::var("RIVE_CPP_PATH")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from("../submodules/rive-cpp"));
 
+    println!(
+        "cargo:rustc-env=RIVE_CPP_COMMIT={}",
+        rive_cpp_commit(&rive_cpp_path)
+    );
+
     cc::Build::new()
         .cpp(true)
         .include(rive_cpp_path.join("include"))
@@ -0,0 +1,2088 @@
+#include "rive/animation/animation_state.hpp"
+#include "rive/animation/any_state.hpp"
+#include "rive/animation/entry_state.hpp"
+#include "rive/animation/exit_state.hpp"
+#include "rive/animation/layer_state.hpp"
+#include "rive/animation/linear_animation.hpp"
+#include "rive/animation/linear_animation_instance.hpp"
+#include "rive/animation/nested_state_machine.hpp"
+#include "rive/animation/state_machine.hpp"
+#include "rive/animation/state_machine_bool.hpp"
+#include "rive/animation/state_machine_input.hpp"
+#include "rive/animation/state_machine_input_instance.hpp"
+#include "rive/animation/state_machine_instance.hpp"
+#include "rive/animation/state_machine_layer.hpp"
+#include "rive/animation/listener_type.hpp"
+#include "rive/animation/state_machine_listener.hpp"
+#include "rive/animation/state_machine_number.hpp"
+#include "rive/animation/state_machine_trigger.hpp"
+#include "rive/artboard.hpp"
+#include "rive/assets/font_asset.hpp"
+#include "rive/assets/image_asset.hpp"
+#include "rive/bones/bone.hpp"
+#include "rive/custom_property_boolean.hpp"
+#include "rive/custom_property_number.hpp"
+#include "rive/custom_property_string.hpp"
+#include "rive/drawable.hpp"
+#include "rive/event.hpp"
+#include "rive/factory.hpp"
+#include "rive/file.hpp"
+#include "rive/generated/core_registry.hpp"
+#include "rive/math/aabb.hpp"
+#include "rive/math/path_types.hpp"
+#include "rive/math/raw_path.hpp"
+#include "rive/math/vec2d.hpp"
+#include "rive/nested_artboard.hpp"
+#include "rive/open_url_event.hpp"
+#include "rive/renderer.hpp"
+#include "rive/shapes/paint/fill.hpp"
+#include "rive/shapes/paint/gradient_stop.hpp"
+#include "rive/shapes/paint/linear_gradient.hpp"
+#include "rive/shapes/paint/shape_paint.hpp"
+#include "rive/shapes/paint/solid_color.hpp"
+#include "rive/shapes/cubic_vertex.hpp"
+#include "rive/shapes/points_path.hpp"
+#include "rive/shapes/shape.hpp"
+#include "rive/solo.hpp"
+#include "rive/text/text.hpp"
+#include "rive/text/text_value_run.hpp"
+#include "rive/text_engine.hpp"
+#include "rive/world_transform_component.hpp"
+
+#include <algorithm>
+#include <cmath>
+#include <cstdint>
+#include <vector>
+
+extern "C"
+{
+    using namespace rive;
+
+    typedef struct RawRustBuffer RawRustBuffer;
+    typedef struct RawRustPath RawRustPath;
+    typedef struct RawRustPaint RawRustPaint;
+    typedef struct RawRustGradient RawRustGradient;
+    typedef struct RawRustImage RawRustImage;
+    typedef struct RawRustFactory RawRustFactory;
+    typedef struct RawRustRenderer RawRustRenderer;
+    typedef struct RawRustString RawRustString;
+    typedef struct RawRustBTreeMap RawRustBTreeMap;
+    typedef struct RawRustAssetCache RawRustAssetCache;
+
+    void rive_rs_allocate_string(const RawRustString* string, const char* data, size_t len);
+
+    typedef struct RawRustVec RawRustVec;
+
+    void rive_rs_push_color(const RawRustVec* colors, ColorInt color);
+    void rive_rs_push_component(const RawRustVec* components, const Core* component);
+    void rive_rs_push_point(const RawRustVec* points, float x, float y);
+    void rive_rs_push_visible_text(const RawRustVec* texts,
+                                   const char* data,
+                                   size_t len,
+                                   const float* bounds);
+    void rive_rs_push_text_line(const RawRustVec* lines,
+                                float top,
+                                float baseline,
+                                float bottom,
+                                float width);
+
+    enum class AssetTag : uint8_t
+    {
+        Image,
+        Font,
+    };
+
+    void rive_rs_push_asset(const RawRustVec* assets,
+                            AssetTag tag,
+                            const char* data,
+                            size_t len,
+                            bool is_loaded);
+
+    typedef struct RawString
+    {
+        const char* data;
+        size_t len;
+    } RawString;
+    enum class PropertyTag : uint8_t
+    {
+        Bool,
+        Number,
+        String,
+    };
+    typedef union Property
+    {
+        bool boolean;
+        float number;
+        RawString string;
+    } Property;
+
+    void rive_rs_insert_property(const RawRustBTreeMap* properties,
+                                 const char* key_data,
+                                 size_t key_len,
+                                 PropertyTag value_tag,
+                                 Property value_payload);
+
+    typedef struct RendererEntries
+    {
+        const RawRustBuffer* (*buffer_new)(RenderBufferType, RenderBufferFlags, size_t);
+        void (*buffer_release)(const RawRustBuffer*);
+        uint8_t* (*buffer_map)(const RawRustBuffer*);
+        void (*buffer_unmap)(const RawRustBuffer*);
+        const RawRustPath* (*path_default)();
+        const RawRustPath* (*path_new)(RawPath::Iter*, size_t, FillRule);
+        void (*path_release)(const RawRustPath*);
+        void (*path_reset)(const RawRustPath*);
+        void (*path_extend)(const RawRustPath*, const RawRustPath*, const float* transform);
+        void (*path_set_fill_rule)(const RawRustPath*, FillRule);
+        void (*path_move_to)(const RawRustPath*, float, float);
+        void (*path_line_to)(const RawRustPath*, float, float);
+        void (*path_cubic_to)(const RawRustPath*, float, float, float, float, float, float);
+        void (*path_close)(const RawRustPath*);
+        const RawRustPaint* (*paint_default)();
+        void (*paint_release)(const RawRustPaint*);
+        void (*paint_set_style)(const RawRustPaint*, RenderPaintStyle);
+        void (*paint_set_color)(const RawRustPaint*, ColorInt);
+        void (*paint_set_thickness)(const RawRustPaint*, float);
+        void (*paint_set_join)(const RawRustPaint*, StrokeJoin);
+        void (*paint_set_cap)(const RawRustPaint*, StrokeCap);
+        void (*paint_set_blend_mode)(const RawRustPaint*, BlendMode);
+        void (*paint_set_gradient)(const RawRustPaint*, const RawRustGradient*);
+        void (*paint_invalidate_stroke)(const RawRustPaint*);
+        const RawRustGradient* (*gradient_new_linear)(float,
+                                                      float,
+                                                      float,
+                                                      float,
+                                                      const ColorInt*,
+                                                      const float*,
+                                                      size_t);
+        const RawRustGradient* (
+            *gradient_new_radial)(float, float, float, const ColorInt*, const float*, size_t);
+        void (*gradient_release)(const RawRustGradient*);
+        const RawRustImage* (*image_decode)(RawRustAssetCache*, const uint8_t*, size_t);
+        void (*image_release)(const RawRustImage*);
+        const Font* (*font_cached)(RawRustAssetCache*, const uint8_t*, size_t);
+        void (*font_insert)(RawRustAssetCache*, const uint8_t*, size_t, const Font*);
+        void (*renderer_state_push)(const RawRustRenderer*);
+        void (*renderer_state_pop)(const RawRustRenderer*);
+        void (*renderer_transform)(const RawRustRenderer*, const float*);
+        void (*renderer_set_clip)(const RawRustRenderer*, const RawRustPath*);
+        void (*renderer_draw_path)(const RawRustRenderer*, const RawRustPath*, const RawRustPaint*);
+        void (*renderer_draw_image)(const RawRustRenderer*, const RawRustImage*, BlendMode, float);
+        void (*renderer_draw_image_mesh)(const RawRustRenderer*,
+                                         const RawRustImage*,
+                                         const RawRustBuffer*,
+                                         const RawRustBuffer*,
+                                         const RawRustBuffer*,
+                                         BlendMode,
+                                         float);
+    } RendererEntries;
+
+    class RustBuffer : public lite_rtti_override<RenderBuffer, RustBuffer>
+    {
+    private:
+        const RawRustBuffer* m_buffer;
+        const RendererEntries* m_entries;
+
+    public:
+        RustBuffer(RenderBufferType type,
+                   RenderBufferFlags flags,
+                   size_t sizeInBytes,
+                   const RendererEntries* entries) :
+            lite_rtti_override(type, flags, sizeInBytes),
+            m_buffer(entries->buffer_new(type, flags, sizeInBytes)),
+            m_entries(entries)
+        {}
+        ~RustBuffer() override { m_entries->buffer_release(m_buffer); }
+
+        const RawRustBuffer* buffer() const { return m_buffer; }
+
+        void* onMap() override { return static_cast<void*>(m_entries->buffer_map(m_buffer)); }
+        void onUnmap() override { m_entries->buffer_unmap(m_buffer); }
+    };
+
+    class RustShader : public lite_rtti_override<RenderShader, RustShader>
+    {
+    private:
+        const RawRustGradient* m_gradient;
+        const RendererEntries* m_entries;
+
+    public:
+        RustShader(const RawRustGradient* gradient, const RendererEntries* entries) :
+            m_gradient(gradient), m_entries(entries)
+        {}
+        ~RustShader() override { m_entries->gradient_release(m_gradient); }
+
+        const RawRustGradient* gradient() const { return m_gradient; }
+    };
+
+    class RustImage : public lite_rtti_override<RenderImage, RustImage>
+    {
+    private:
+        const RawRustImage* m_image;
+        const RendererEntries* m_entries;
+
+    public:
+        RustImage(const RawRustImage* image, const RendererEntries* entries) :
+            m_image(image), m_entries(entries)
+        {}
+        ~RustImage() override { m_entries->image_release(m_image); }
+
+        const RawRustImage* image() const { return m_image; }
+    };
+
+    class RustPath : public lite_rtti_override<RenderPath, RustPath>
+    {
+    private:
+        const RawRustPath* m_path;
+        const RendererEntries* m_entries;
+
+    public:
+        RustPath(const RawRustPath* path, const RendererEntries* entries) :
+            m_path(path), m_entries(entries)
+        {}
+        ~RustPath() override { m_entries->path_release(m_path); }
+
+        const RawRustPath* path() const { return m_path; }
+
+        void rewind() override { m_entries->path_reset(m_path); }
+        void addRenderPath(RenderPath* path, const Mat2D& transform) override
+        {
+            LITE_RTTI_CAST_OR_RETURN(rustPath, RustPath*, path);
+            m_entries->path_extend(m_path, rustPath->m_path, transform.values());
+        }
+        void fillRule(FillRule value) override { m_entries->path_set_fill_rule(m_path, value); }
+        void moveTo(float x, float y) override { m_entries->path_move_to(m_path, x, y); }
+        void lineTo(float x, float y) override { m_entries->path_line_to(m_path, x, y); }
+        void cubicTo(float ox, float oy, float ix, float iy, float x, float y) override
+        {
+            m_entries->path_cubic_to(m_path, ox, oy, ix, iy, x, y);
+        }
+        virtual void close() override { m_entries->path_close(m_path); }
+    };
+
+    class RustPaint : public lite_rtti_override<RenderPaint, RustPaint>
+    {
+    private:
+        const RawRustPaint* m_paint;
+        const RendererEntries* m_entries;
+
+    public:
+        RustPaint(const RawRustPaint* paint, const RendererEntries* entries) :
+            m_paint(paint), m_entries(entries)
+        {}
+        ~RustPaint() override { m_entries->paint_release(m_paint); }
+
+        const RawRustPaint* paint() const { return m_paint; }
+
+        void style(RenderPaintStyle style) override { m_entries->paint_set_style(m_paint, style); }
+        void color(unsigned int value) override { m_entries->paint_set_color(m_paint, value); }
+        void thickness(float value) override { m_entries->paint_set_thickness(m_paint, value); }
+        void join(StrokeJoin value) override { m_entries->paint_set_join(m_paint, value); }
+        void cap(StrokeCap value) override { m_entries->paint_set_cap(m_paint, value); }
+        void blendMode(BlendMode value) override
+        {
+            m_entries->paint_set_blend_mode(m_paint, value);
+        }
+        void shader(rcp<RenderShader> shader) override
+        {
+            auto rustShader = lite_rtti_cast<RustShader*>(shader.get());
+
+            if (rustShader)
+            {
+                m_entries->paint_set_gradient(m_paint, rustShader->gradient());
+            }
+        }
+        void invalidateStroke() override { m_entries->paint_invalidate_stroke(m_paint); }
+    };
+
+    class RustFactory : public Factory
+    {
+    private:
+        const RendererEntries* m_entries;
+        RawRustAssetCache* m_asset_cache = nullptr;
+
+    public:
+        RustFactory(const RendererEntries* entries) : m_entries(entries) {}
+
+        // The cache is only borrowed while importing a file.
+        void setAssetCache(RawRustAssetCache* asset_cache) { m_asset_cache = asset_cache; }
+
+        rcp<RenderBuffer> makeRenderBuffer(RenderBufferType type,
+                                           RenderBufferFlags flags,
+                                           size_t len_in_bytes) override
+        {
+            return rcp<RenderBuffer>(new RustBuffer(type, flags, len_in_bytes, m_entries));
+        }
+
+        rcp<RenderShader> makeLinearGradient(float sx,
+                                             float sy,
+                                             float ex,
+                                             float ey,
+                                             const ColorInt colors[],
+                                             const float stops[],
+                                             size_t count) override
+        {
+            const RawRustGradient* gradient =
+                m_entries->gradient_new_linear(sx, sy, ex, ey, colors, stops, count);
+            return rcp<RenderShader>(new RustShader(std::move(gradient), m_entries));
+        }
+
+        rcp<RenderShader> makeRadialGradient(float cx,
+                                             float cy,
+                                             float radius,
+                                             const ColorInt colors[],
+                                             const float stops[],
+                                             size_t count) override
+        {
+            const RawRustGradient* gradient =
+                m_entries->gradient_new_radial(cx, cy, radius, colors, stops, count);
+            return rcp<RenderShader>(new RustShader(std::move(gradient), m_entries));
+        }
+
+        std::unique_ptr<RenderPath> makeRenderPath(RawPath& path, FillRule fill_rule) override
+        {
+            auto iter = path.begin();
+            return std::make_unique<RustPath>(
+                m_entries->path_new(&iter, path.verbs().size(), fill_rule),
+                m_entries);
+        }
+
+        std::unique_ptr<RenderPath> makeEmptyRenderPath() override
+        {
+            return std::make_unique<RustPath>(m_entries->path_default(), m_entries);
+        }
+
+        std::unique_ptr<RenderPaint> makeRenderPaint() override
+        {
+            return std::make_unique<RustPaint>(m_entries->paint_default(), m_entries);
+        }
+
+        rcp<RenderImage> decodeImage(Span<const uint8_t> encoded) override
+        {
+            const RawRustImage* image =
+                m_entries->image_decode(m_asset_cache, encoded.data(), encoded.size());
+
+            // Leaves the asset without an image, so that it is reported as unresolved and skipped
+            // when drawing instead of handing a null image to the renderer.
+            if (image == nullptr)
+            {
+                return nullptr;
+            }
+
+            return make_rcp<RustImage>(image, m_entries);
+        }
+
+        rcp<Font> decodeFont(Span<const uint8_t> encoded) override
+        {
+            if (!m_asset_cache)
+            {
+                return Factory::decodeFont(encoded);
+            }
+
+            const Font* cached =
+                m_entries->font_cached(m_asset_cache, encoded.data(), encoded.size());
+            if (cached)
+            {
+                cached->ref();
+                return rcp<Font>(const_cast<Font*>(cached));
+            }
+
+            auto font = Factory::decodeFont(encoded);
+            if (font)
+            {
+                // The cache keeps its own reference.
+                font->ref();
+                m_entries->font_insert(m_asset_cache, encoded.data(), encoded.size(), font.get());
+            }
+
+            return font;
+        }
+    };
+
+    class RustRenderer : public Renderer
+    {
+    private:
+        const RawRustRenderer* m_renderer;
+        const RendererEntries* m_entries;
+
+    public:
+        RustRenderer(const RawRustRenderer* renderer, const RendererEntries* entries) :
+            m_renderer(renderer), m_entries(entries)
+        {}
+        ~RustRenderer() override {}
+
+        void save() override { m_entries->renderer_state_push(m_renderer); }
+        void restore() override { m_entries->renderer_state_pop(m_renderer); }
+        void transform(const Mat2D& transform) override
+        {
+            m_entries->renderer_transform(m_renderer, transform.values());
+        }
+        void clipPath(RenderPath* path) override
+        {
+            LITE_RTTI_CAST_OR_RETURN(rustPath, RustPath*, path);
+            m_entries->renderer_set_clip(m_renderer, rustPath->path());
+        }
+        void drawPath(RenderPath* path, RenderPaint* paint) override
+        {
+            LITE_RTTI_CAST_OR_RETURN(rustPath, RustPath*, path);
+            LITE_RTTI_CAST_OR_RETURN(rustPaint, RustPaint*, paint);
+            m_entries->renderer_draw_path(m_renderer, rustPath->path(), rustPaint->paint());
+        }
+        void drawImage(const RenderImage* image, BlendMode blend_mode, float opacity) override
+        {
+            LITE_RTTI_CAST_OR_RETURN(rustImage, const RustImage*, image);
+            m_entries->renderer_draw_image(m_renderer, rustImage->image(), blend_mode, opacity);
+        }
+        void drawImageMesh(const RenderImage* image,
+                           rcp<RenderBuffer> vertices_f32,
+                           rcp<RenderBuffer> uvCoords_f32,
+                           rcp<RenderBuffer> indices_u16,
+                           uint32_t,
+                           uint32_t,
+                           BlendMode blend_mode,
+                           float opacity) override
+        {
+            LITE_RTTI_CAST_OR_RETURN(rustImage, const RustImage*, image);
+            LITE_RTTI_CAST_OR_RETURN(rustVertices, RustBuffer*, vertices_f32.get());
+            LITE_RTTI_CAST_OR_RETURN(rustUVCoords, RustBuffer*, uvCoords_f32.get());
+            LITE_RTTI_CAST_OR_RETURN(rustIndices, RustBuffer*, indices_u16.get());
+            m_entries->renderer_draw_image_mesh(m_renderer,
+                                                rustImage->image(),
+                                                rustVertices->buffer(),
+                                                rustUVCoords->buffer(),
+                                                rustIndices->buffer(),
+                                                blend_mode,
+                                                opacity);
+        }
+    };
+
+    typedef struct Command
+    {
+        PathVerb verb;
+        const Vec2D* points;
+    } Command;
+
+    enum class InputTag : uint8_t
+    {
+        Bool,
+        Number,
+        Trigger,
+    };
+
+    const File* rive_rs_file_new(const uint8_t* data,
+                                 size_t len,
+                                 const RendererEntries* entries,
+                                 RawRustAssetCache* asset_cache,
+                                 ImportResult* result,
+                                 RustFactory** factory)
+    {
+        RustFactory* rust_factory = new RustFactory(entries);
+
+        rust_factory->setAssetCache(asset_cache);
+        auto file = rive::File::import({data, len}, rust_factory, result);
+        rust_factory->setAssetCache(nullptr);
+
+        *factory = rust_factory;
+
+        return static_cast<const File*>(file.release());
+    }
+
+    void rive_rs_file_release(const File* file, RustFactory* factory)
+    {
+        std::unique_ptr<File> val(std::move(const_cast<File*>(file)));
+        delete factory;
+    }
+
+    void rive_rs_font_release(const Font* font) { font->unref(); }
+
+    void rive_rs_file_assets(const File* file, const RawRustVec* assets)
+    {
+        for (auto asset : file->assets())
+        {
+            if (asset->is<ImageAsset>())
+            {
+                rive_rs_push_asset(assets,
+                                   AssetTag::Image,
+                                   asset->name().data(),
+                                   asset->name().size(),
+                                   asset->as<ImageAsset>()->renderImage() != nullptr);
+            }
+            else if (asset->is<FontAsset>())
+            {
+                rive_rs_push_asset(assets,
+                                   AssetTag::Font,
+                                   asset->name().data(),
+                                   asset->name().size(),
+                                   asset->as<FontAsset>()->font() != nullptr);
+            }
+        }
+    }
+
+    int32_t rive_rs_property_field_id(uint32_t property_key)
+    {
+        return CoreRegistry::propertyFieldId(property_key);
+    }
+
+    bool rive_rs_is_type_key_known(uint32_t type_key)
+    {
+        auto object = CoreRegistry::makeCoreInstance(type_key);
+        bool is_known = object != nullptr;
+        delete object;
+
+        return is_known;
+    }
+
+    uint32_t rive_rs_file_major_version() { return File::majorVersion; }
+
+    uint32_t rive_rs_file_minor_version() { return File::minorVersion; }
+
+    // Replaces every solid color and gradient stop color of `artboard` found in `from` with the
+    // color at the same index in `to`.
+    static void remap_colors(Artboard* artboard,
+                             const ColorInt* from,
+                             const ColorInt* to,
+                             size_t len)
+    {
+        for (auto object : artboard->objects())
+        {
+            if (object == nullptr)
+            {
+                continue;
+            }
+
+            for (size_t i = 0; i < len; ++i)
+            {
+                if (object->is<SolidColor>() && object->as<SolidColor>()->colorValue() == from[i])
+                {
+                    object->as<SolidColor>()->colorValue(to[i]);
+                    break;
+                }
+
+                if (object->is<GradientStop>() &&
+                    object->as<GradientStop>()->colorValue() == from[i])
+                {
+                    object->as<GradientStop>()->colorValue(to[i]);
+                    break;
+                }
+            }
+        }
+    }
+
+    void rive_rs_file_remap_colors(const File* file,
+                                   const ColorInt* from,
+                                   const ColorInt* to,
+                                   size_t len)
+    {
+        auto mutable_file = const_cast<File*>(file);
+        for (size_t i = 0; i < mutable_file->artboardCount(); ++i)
+        {
+            remap_colors(mutable_file->artboard(i), from, to, len);
+        }
+    }
+
+    void rive_rs_instantiate_artboard(const File* file,
+                                      const size_t* index,
+                                      ArtboardInstance** artboard_instance)
+    {
+        if (index)
+        {
+            if (*index < file->artboardCount())
+            {
+                *artboard_instance = file->artboardAt(*index).release();
+            }
+        }
+        else
+        {
+            auto ptr = file->artboardDefault();
+            if (ptr)
+            {
+                *artboard_instance = ptr.release();
+            }
+        }
+
+        if (*artboard_instance)
+        {
+            (*artboard_instance)->advance(0.0f);
+        }
+    }
+
+    void rive_rs_instantiate_artboard_by_name(const File* file,
+                                              const char* data,
+                                              size_t len,
+                                              ArtboardInstance** artboard_instance)
+    {
+        *artboard_instance = file->artboardNamed({data, len}).release();
+
+        if (*artboard_instance)
+        {
+            (*artboard_instance)->advance(0.0f);
+        }
+    }
+
+    size_t rive_rs_file_artboard_count(const File* file) { return file->artboardCount(); }
+
+    void rive_rs_file_artboard_name(const File* file,
+                                    size_t index,
+                                    const char** data,
+                                    size_t* len)
+    {
+        auto artboard = file->artboard(index);
+        *data = artboard->name().data();
+        *len = artboard->name().size();
+    }
+
+    void rive_rs_artboard_instance_release(const ArtboardInstance* artboard_instance)
+    {
+        std::unique_ptr<ArtboardInstance> val(
+            std::move(const_cast<ArtboardInstance*>(artboard_instance)));
+    }
+
+    ArtboardInstance* rive_rs_artboard_duplicate(const ArtboardInstance* artboard_instance)
+    {
+        // Cloning an instance copies the current values of its objects' properties.
+        auto duplicate = artboard_instance->instance().release();
+        duplicate->advance(0.0f);
+
+        return duplicate;
+    }
+
+    size_t rive_rs_artboard_component_count(const ArtboardInstance* artboard_instance)
+    {
+        return artboard_instance->objects().size();
+    }
+
+    const Core* rive_rs_artboard_get_component(const ArtboardInstance* artboard_instance,
+                                               size_t index)
+    {
+        return artboard_instance->objects()[index];
+    }
+
+    float rive_rs_artboard_width(const ArtboardInstance* artboard_instance)
+    {
+        return artboard_instance->width();
+    }
+
+    float rive_rs_artboard_height(const ArtboardInstance* artboard_instance)
+    {
+        return artboard_instance->height();
+    }
+
+    void rive_rs_artboard_advance(ArtboardInstance* artboard_instance)
+    {
+        artboard_instance->advance(0);
+    }
+
+    Loop rive_rs_artboard_loop(const ArtboardInstance* artboard_instance)
+    {
+        if (artboard_instance->animationCount() == 0)
+        {
+            return Loop::oneShot;
+        }
+
+        return static_cast<Loop>(artboard_instance->animation(0)->loopValue());
+    }
+
+    size_t rive_rs_artboard_linear_animation_count(const ArtboardInstance* artboard_instance)
+    {
+        return artboard_instance->animationCount();
+    }
+
+    void rive_rs_artboard_linear_animation_name(const ArtboardInstance* artboard_instance,
+                                                size_t index,
+                                                const char** data,
+                                                size_t* len)
+    {
+        auto linear_animation = artboard_instance->animation(index);
+        *data = linear_animation->name().data();
+        *len = linear_animation->name().size();
+    }
+
+    size_t rive_rs_artboard_state_machine_count(const ArtboardInstance* artboard_instance)
+    {
+        return artboard_instance->stateMachineCount();
+    }
+
+    void rive_rs_artboard_state_machine_name(const ArtboardInstance* artboard_instance,
+                                             size_t index,
+                                             const char** data,
+                                             size_t* len)
+    {
+        auto state_machine = artboard_instance->stateMachine(index);
+        *data = state_machine->name().data();
+        *len = state_machine->name().size();
+    }
+
+    void rive_rs_artboard_draw(ArtboardInstance* artboard_instance,
+                               const RawRustRenderer* renderer,
+                               const RendererEntries* entries)
+    {
+        RustRenderer rust_renderer(renderer, entries);
+        artboard_instance->draw(&rust_renderer, Artboard::DrawOption::kNormal);
+    }
+
+    // Collects every fill and stroke of the components named `name`: either the components
+    // themselves or their direct children.
+    static std::vector<ShapePaint*> named_shape_paints(ArtboardInstance* artboard_instance,
+                                                       const char* data,
+                                                       size_t len)
+    {
+        std::string name(data, len);
+        std::vector<ShapePaint*> shape_paints;
+
+        for (auto object : artboard_instance->objects())
+        {
+            if (object == nullptr || !object->is<Component>() ||
+                object->as<Component>()->name() != name)
+            {
+                continue;
+            }
+
+            if (object->is<ShapePaint>())
+            {
+                shape_paints.push_back(object->as<ShapePaint>());
+            }
+            else if (object->is<ContainerComponent>())
+            {
+                for (auto child : object->as<ContainerComponent>()->children())
+                {
+                    if (child->is<ShapePaint>())
+                    {
+                        shape_paints.push_back(child->as<ShapePaint>());
+                    }
+                }
+            }
+        }
+
+        return shape_paints;
+    }
+
+    void rive_rs_artboard_draw_order(ArtboardInstance* artboard_instance,
+                                     const RawRustVec* drawables)
+    {
+        for (auto drawable = artboard_instance->firstDrawable(); drawable != nullptr;
+             drawable = drawable->prev)
+        {
+            rive_rs_push_component(drawables, drawable);
+        }
+    }
+
+    void rive_rs_artboard_bounds(const ArtboardInstance* artboard_instance, float* bounds)
+    {
+        // `Artboard::bounds` is relative to the frame while it is framed by its origin, which is
+        // always the case for instances, so compute the rectangle from the origin instead.
+        float width = artboard_instance->width();
+        float height = artboard_instance->height();
+
+        bounds[0] = -artboard_instance->originX() * width;
+        bounds[1] = -artboard_instance->originY() * height;
+        bounds[2] = bounds[0] + width;
+        bounds[3] = bounds[1] + height;
+    }
+
+    bool rive_rs_artboard_background_color(const ArtboardInstance* artboard_instance,
+                                           ColorInt* color)
+    {
+        for (auto object : artboard_instance->objects())
+        {
+            // Strokes of the artboard only outline it.
+            if (object == nullptr || !object->is<Fill>() || object->parent() != artboard_instance)
+            {
+                continue;
+            }
+
+            for (auto child : object->as<Fill>()->children())
+            {
+                if (child->is<SolidColor>())
+                {
+                    *color = child->as<SolidColor>()->colorValue();
+                    return true;
+                }
+            }
+        }
+
+        return false;
+    }
+
+    static void push_world_bounds(const Shape* shape, const RawRustVec* points)
+    {
+        auto bounds = shape->computeWorldBounds();
+
+        rive_rs_push_point(points, bounds.minX, bounds.minY);
+        rive_rs_push_point(points, bounds.maxX, bounds.maxY);
+    }
+
+    void rive_rs_artboard_shape_bounds(const ArtboardInstance* artboard_instance,
+                                       const RawRustVec* points)
+    {
+        for (auto object : artboard_instance->objects())
+        {
+            if (object != nullptr && object->is<Shape>())
+            {
+                push_world_bounds(object->as<Shape>(), points);
+            }
+        }
+    }
+
+    void rive_rs_artboard_bones(const ArtboardInstance* artboard_instance,
+                                const RawRustVec* points)
+    {
+        for (auto object : artboard_instance->objects())
+        {
+            if (object == nullptr || !object->is<Bone>())
+            {
+                continue;
+            }
+
+            auto bone = object->as<Bone>();
+            auto start = bone->worldTransform().translation();
+            auto end = bone->worldTransform() * Vec2D(bone->length(), 0.0f);
+
+            rive_rs_push_point(points, start.x, start.y);
+            rive_rs_push_point(points, end.x, end.y);
+        }
+    }
+
+    static void text_world_bounds(const Text* text, float* bounds)
+    {
+        auto local_bounds = text->localBounds();
+        auto world_transform = text->worldTransform();
+        Vec2D corners[] = {
+            world_transform * Vec2D(local_bounds.minX, local_bounds.minY),
+            world_transform * Vec2D(local_bounds.maxX, local_bounds.minY),
+            world_transform * Vec2D(local_bounds.maxX, local_bounds.maxY),
+            world_transform * Vec2D(local_bounds.minX, local_bounds.maxY),
+        };
+
+        bounds[0] = bounds[2] = corners[0].x;
+        bounds[1] = bounds[3] = corners[0].y;
+        for (auto corner : corners)
+        {
+            bounds[0] = std::min(bounds[0], corner.x);
+            bounds[1] = std::min(bounds[1], corner.y);
+            bounds[2] = std::max(bounds[2], corner.x);
+            bounds[3] = std::max(bounds[3], corner.y);
+        }
+    }
+
+    void rive_rs_artboard_visible_text(ArtboardInstance* artboard_instance,
+                                       const RawRustVec* texts)
+    {
+        for (auto drawable = artboard_instance->firstDrawable(); drawable != nullptr;
+             drawable = drawable->prev)
+        {
+            if (!drawable->is<Text>() || drawable->isHidden())
+            {
+                continue;
+            }
+
+            auto text = drawable->as<Text>();
+
+            std::string content;
+            for (auto child : text->children())
+            {
+                if (child->is<TextValueRun>())
+                {
+                    content += child->as<TextValueRun>()->text();
+                }
+            }
+
+            if (content.empty())
+            {
+                continue;
+            }
+
+            float bounds[4];
+            text_world_bounds(text, bounds);
+
+            rive_rs_push_visible_text(texts, content.data(), content.size(), bounds);
+        }
+    }
+
+#ifdef WITH_RIVE_TEXT
+    // Number of code points in the UTF-8 `text`, which glyphs index the text with.
+    static uint32_t code_point_count(const std::string& text)
+    {
+        uint32_t count = 0;
+        for (unsigned char byte : text)
+        {
+            if ((byte & 0xC0) != 0x80)
+            {
+                ++count;
+            }
+        }
+
+        return count;
+    }
+
+    // Pushes the metrics of every line of `text` and computes the bounds of the glyphs of `run`
+    // from the last layout, in the text object's layout coordinates.
+    static void measure_lines(const Text* text,
+                              const TextValueRun* run,
+                              const RawRustVec* lines,
+                              float* run_bounds,
+                              bool* has_run_bounds)
+    {
+        // Glyphs index the concatenated text of all the runs of the text object.
+        uint32_t run_start = 0;
+        for (auto other : text->runs())
+        {
+            if (other == run)
+            {
+                break;
+            }
+
+            run_start += code_point_count(other->text());
+        }
+        uint32_t run_end = run_start + code_point_count(run->text());
+
+        const auto& paragraphs = text->shape();
+        const auto& paragraph_lines = text->lines();
+
+        for (size_t p = 0; p < paragraphs.size() && p < paragraph_lines.size(); ++p)
+        {
+            const auto& glyph_runs = paragraphs[p].runs;
+
+            for (const auto& line : paragraph_lines[p])
+            {
+                float line_start = glyph_runs[line.startRunIndex].xpos[line.startGlyphIndex];
+                float line_end = glyph_runs[line.endRunIndex].xpos[line.endGlyphIndex];
+
+                rive_rs_push_text_line(lines,
+                                       line.top,
+                                       line.baseline,
+                                       line.bottom,
+                                       line_end - line_start);
+
+                for (uint32_t r = line.startRunIndex; r <= line.endRunIndex; ++r)
+                {
+                    const auto& glyph_run = glyph_runs[r];
+                    uint32_t first = r == line.startRunIndex ? line.startGlyphIndex : 0;
+                    uint32_t last = r == line.endRunIndex ? line.endGlyphIndex
+                                                          : (uint32_t)glyph_run.glyphs.size();
+
+                    for (uint32_t g = first; g < last; ++g)
+                    {
+                        uint32_t index = glyph_run.textIndices[g];
+                        if (index < run_start || index >= run_end)
+                        {
+                            continue;
+                        }
+
+                        float min_x = line.startX + glyph_run.xpos[g] - line_start;
+                        float max_x = line.startX + glyph_run.xpos[g + 1] - line_start;
+
+                        if (!*has_run_bounds)
+                        {
+                            run_bounds[0] = min_x;
+                            run_bounds[1] = line.top;
+                            run_bounds[2] = max_x;
+                            run_bounds[3] = line.bottom;
+                            *has_run_bounds = true;
+                        }
+                        else
+                        {
+                            run_bounds[0] = std::min(run_bounds[0], min_x);
+                            run_bounds[1] = std::min(run_bounds[1], line.top);
+                            run_bounds[2] = std::max(run_bounds[2], max_x);
+                            run_bounds[3] = std::max(run_bounds[3], line.bottom);
+                        }
+                    }
+                }
+            }
+        }
+    }
+#endif
+
+    bool rive_rs_artboard_measure_text_run(ArtboardInstance* artboard_instance,
+                                           const char* data,
+                                           size_t len,
+                                           float* bounds,
+                                           float* size,
+                                           const RawRustVec* lines,
+                                           float* run_bounds,
+                                           bool* has_run_bounds)
+    {
+        auto run = artboard_instance->find<TextValueRun>(std::string(data, len));
+        if (run == nullptr || run->parent() == nullptr || !run->parent()->is<Text>())
+        {
+            return false;
+        }
+
+        auto text = run->parent()->as<Text>();
+        text_world_bounds(text, bounds);
+
+        auto local_bounds = text->localBounds();
+        size[0] = local_bounds.width();
+        size[1] = local_bounds.height();
+
+        *has_run_bounds = false;
+#ifdef WITH_RIVE_TEXT
+        measure_lines(text, run, lines, run_bounds, has_run_bounds);
+#endif
+
+        return true;
+    }
+
+    bool rive_rs_artboard_set_color(ArtboardInstance* artboard_instance,
+                                    const char* data,
+                                    size_t len,
+                                    ColorInt color)
+    {
+        bool found = false;
+        for (auto shape_paint : named_shape_paints(artboard_instance, data, len))
+        {
+            for (auto child : shape_paint->children())
+            {
+                if (child->is<SolidColor>())
+                {
+                    child->as<SolidColor>()->colorValue(color);
+                    found = true;
+                }
+            }
+        }
+
+        return found;
+    }
+
+    void rive_rs_artboard_colors(const ArtboardInstance* artboard_instance,
+                                 const RawRustVec* colors)
+    {
+        for (auto object : artboard_instance->objects())
+        {
+            if (object == nullptr)
+            {
+                continue;
+            }
+
+            if (object->is<SolidColor>())
+            {
+                rive_rs_push_color(colors, object->as<SolidColor>()->colorValue());
+            }
+            else if (object->is<GradientStop>())
+            {
+                rive_rs_push_color(colors, object->as<GradientStop>()->colorValue());
+            }
+        }
+    }
+
+    void rive_rs_artboard_remap_colors(ArtboardInstance* artboard_instance,
+                                       const ColorInt* from,
+                                       const ColorInt* to,
+                                       size_t len)
+    {
+        remap_colors(artboard_instance, from, to, len);
+    }
+
+    bool rive_rs_artboard_set_gradient_stop_color(ArtboardInstance* artboard_instance,
+                                                  const char* data,
+                                                  size_t len,
+                                                  size_t index,
+                                                  ColorInt color)
+    {
+        bool found = false;
+        for (auto shape_paint : named_shape_paints(artboard_instance, data, len))
+        {
+            for (auto child : shape_paint->children())
+            {
+                if (!child->is<LinearGradient>())
+                {
+                    continue;
+                }
+
+                size_t stop_index = 0;
+                for (auto stop : child->as<LinearGradient>()->children())
+                {
+                    if (stop->is<GradientStop>() && stop_index++ == index)
+                    {
+                        stop->as<GradientStop>()->colorValue(color);
+                        found = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        return found;
+    }
+
+    uint16_t rive_rs_component_type_id(const Core* component) { return component->coreType(); }
+
+    ArtboardInstance* rive_rs_component_nested_artboard(Core* component)
+    {
+        if (!component->is<NestedArtboard>())
+        {
+            return nullptr;
+        }
+
+        return component->as<NestedArtboard>()->artboardInstance();
+    }
+
+    bool rive_rs_component_parent(const Core* component, uint32_t* parent)
+    {
+        if (!component->is<Component>() || component->is<Artboard>())
+        {
+            return false;
+        }
+
+        *parent = component->as<Component>()->parentId();
+        return true;
+    }
+
+    bool rive_rs_component_world_transform(const Core* component, float* transform)
+    {
+        if (!component->is<WorldTransformComponent>())
+        {
+            return false;
+        }
+
+        auto world_transform = component->as<WorldTransformComponent>()->worldTransform();
+        for (size_t i = 0; i < 6; ++i)
+        {
+            transform[i] = world_transform[i];
+        }
+
+        return true;
+    }
+
+    void rive_rs_component_name(const Component* component, const char** data, size_t* len)
+    {
+        if (static_cast<const Core*>(component)->is<Component>())
+        {
+            *data = component->name().data();
+            *len = component->name().size();
+        }
+        else
+        {
+            *len = 0;
+        }
+    }
+
+    void rive_rs_text_value_run_get_text(const TextValueRun* text_value_run,
+                                         const char** data,
+                                         size_t* len)
+    {
+        *data = text_value_run->text().data();
+        *len = text_value_run->text().size();
+    }
+
+    void rive_rs_text_value_run_set_text(TextValueRun* text_value_run, const char* data, size_t len)
+    {
+        text_value_run->text({data, len});
+    }
+
+    bool rive_rs_solo_active_name(const Solo* solo, const char** data, size_t* len)
+    {
+        auto active = solo->artboard()->resolve(solo->activeComponentId());
+
+        if (active == nullptr || !active->is<Component>())
+        {
+            return false;
+        }
+
+        *data = active->as<Component>()->name().data();
+        *len = active->as<Component>()->name().size();
+
+        return true;
+    }
+
+    bool rive_rs_solo_set_active(Solo* solo, const char* data, size_t len)
+    {
+        std::string name(data, len);
+
+        for (auto child : solo->children())
+        {
+            if (child->name() == name)
+            {
+                solo->activeComponentId(solo->artboard()->idOf(child));
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    size_t rive_rs_points_path_vertex_count(const PointsPath* points_path)
+    {
+        return points_path->vertices().size();
+    }
+
+    bool rive_rs_points_path_vertex(PointsPath* points_path, size_t index, float* points)
+    {
+        auto vertex = points_path->vertices()[index];
+
+        points[0] = vertex->x();
+        points[1] = vertex->y();
+
+        if (!vertex->is<CubicVertex>())
+        {
+            return false;
+        }
+
+        auto cubic = vertex->as<CubicVertex>();
+        points[2] = cubic->inPoint().x;
+        points[3] = cubic->inPoint().y;
+        points[4] = cubic->outPoint().x;
+        points[5] = cubic->outPoint().y;
+
+        return true;
+    }
+
+    void rive_rs_points_path_set_position(PointsPath* points_path, size_t index, float x, float y)
+    {
+        auto vertex = points_path->vertices()[index];
+
+        vertex->x(x);
+        vertex->y(y);
+
+        points_path->markPathDirty();
+    }
+
+    bool rive_rs_points_path_set_control_point(PointsPath* points_path,
+                                               size_t index,
+                                               bool is_out,
+                                               float x,
+                                               float y)
+    {
+        auto vertex = points_path->vertices()[index];
+
+        if (!vertex->is<CubicVertex>())
+        {
+            return false;
+        }
+
+        auto cubic = vertex->as<CubicVertex>();
+        if (is_out)
+        {
+            cubic->outPoint(Vec2D(x, y));
+        }
+        else
+        {
+            cubic->inPoint(Vec2D(x, y));
+        }
+
+        points_path->markPathDirty();
+
+        return true;
+    }
+
+    void rive_rs_instantiate_linear_animation(ArtboardInstance* artboard_instance,
+                                              const size_t* index,
+                                              LinearAnimationInstance** linear_animation)
+    {
+        if (index)
+        {
+            if (*index < (size_t)artboard_instance->animationCount())
+            {
+                *linear_animation = artboard_instance->animationAt(*index).release();
+            }
+        }
+        else
+        {
+            auto ptr = artboard_instance->animationAt(0);
+            if (ptr)
+            {
+                *linear_animation = ptr.release();
+            }
+        }
+    }
+
+    void rive_rs_instantiate_linear_animation_by_name(ArtboardInstance* artboard_instance,
+                                                      const char* data,
+                                                      size_t len,
+                                                      LinearAnimationInstance** linear_animation)
+    {
+        *linear_animation = artboard_instance->animationNamed({data, len}).release();
+    }
+
+    LinearAnimationInstance* rive_rs_linear_animation_duplicate(
+        const LinearAnimationInstance* linear_animation,
+        ArtboardInstance* artboard_instance)
+    {
+        auto duplicate =
+            new LinearAnimationInstance(linear_animation->animation(), artboard_instance);
+
+        duplicate->time(linear_animation->time());
+        duplicate->direction(linear_animation->direction());
+        duplicate->loopValue(linear_animation->loopValue());
+
+        return duplicate;
+    }
+
+    float rive_rs_linear_animation_time(const LinearAnimationInstance* linear_animation)
+    {
+        return linear_animation->time();
+    }
+
+    void rive_rs_linear_animation_set_time(LinearAnimationInstance* linear_animation, float time)
+    {
+        linear_animation->time(time);
+    }
+
+    bool rive_rs_linear_animation_is_forwards(const LinearAnimationInstance* linear_animation)
+    {
+        return linear_animation->direction() == 1;
+    }
+
+    void rive_rs_linear_animation_set_is_forwards(LinearAnimationInstance* linear_animation,
+                                                  bool is_forwards)
+    {
+        linear_animation->direction(is_forwards ? 1 : -1);
+    }
+
+    bool rive_rs_linear_animation_advance(LinearAnimationInstance* linear_animation, float elapsed)
+    {
+        return linear_animation->advance(elapsed);
+    }
+
+    void rive_rs_linear_animation_apply(const LinearAnimationInstance* linear_animation, float mix)
+    {
+        linear_animation->apply(mix);
+    }
+
+    void rive_rs_linear_animation_apply_at(const LinearAnimationInstance* linear_animation,
+                                           ArtboardInstance* artboard_instance,
+                                           float time,
+                                           float mix)
+    {
+        linear_animation->animation()->apply(artboard_instance, time, mix);
+    }
+
+    bool rive_rs_linear_animation_did_loop(const LinearAnimationInstance* linear_animation)
+    {
+        return linear_animation->didLoop();
+    }
+
+    void rive_rs_linear_animation_set_loop(LinearAnimationInstance* linear_animation, Loop loop)
+    {
+        linear_animation->loopValue(static_cast<int>(loop));
+    }
+
+    bool rive_rs_linear_animation_is_done(const LinearAnimationInstance* linear_animation)
+    {
+        return !linear_animation->keepGoing();
+    }
+
+    uint32_t rive_rs_linear_animation_fps(const LinearAnimationInstance* linear_animation)
+    {
+        return linear_animation->animation()->fps();
+    }
+
+    uint32_t rive_rs_linear_animation_duration(const LinearAnimationInstance* linear_animation)
+    {
+        return linear_animation->animation()->duration();
+    }
+
+    bool rive_rs_linear_animation_work_area(const LinearAnimationInstance* linear_animation,
+                                            uint32_t* start,
+                                            uint32_t* end)
+    {
+        auto animation = linear_animation->animation();
+
+        *start = animation->workStart();
+        *end = animation->workEnd();
+
+        return animation->enableWorkArea();
+    }
+
+    // Returns whether `linear_animation` plays towards later frames, taking the sign of its
+    // speed into account.
+    static bool plays_forwards(const LinearAnimationInstance* linear_animation)
+    {
+        int direction = linear_animation->direction();
+        if (linear_animation->animation()->speed() < 0.0f)
+        {
+            direction = -direction;
+        }
+
+        return direction == 1;
+    }
+
+    // Moves the time of `linear_animation` to the frame it starts playing `start..end` from if it
+    // is outside of that range.
+    void rive_rs_linear_animation_enter_range(LinearAnimationInstance* linear_animation,
+                                              uint32_t start,
+                                              uint32_t end)
+    {
+        float fps = static_cast<float>(linear_animation->animation()->fps());
+        float frames = linear_animation->time() * fps;
+
+        if (frames < static_cast<float>(start) || frames > static_cast<float>(end))
+        {
+            uint32_t frame = plays_forwards(linear_animation) ? start : end;
+            linear_animation->time(static_cast<float>(frame) / fps);
+        }
+    }
+
+    // Advances `linear_animation` like `LinearAnimationInstance::advance`, but within the frames
+    // `start..end` instead of the work area of the animation, which is shared by all of its
+    // instances.
+    bool rive_rs_linear_animation_advance_in(LinearAnimationInstance* linear_animation,
+                                             float elapsed,
+                                             uint32_t start,
+                                             uint32_t end,
+                                             bool* did_loop)
+    {
+        auto animation = linear_animation->animation();
+        float fps = static_cast<float>(animation->fps());
+        int direction = linear_animation->direction();
+
+        float frames = (linear_animation->time() + elapsed * animation->speed() * direction) * fps;
+        float start_frame = static_cast<float>(start);
+        float end_frame = static_cast<float>(end);
+        float range = end_frame - start_frame;
+        bool keep_going = true;
+        *did_loop = false;
+
+        if (range <= 0.0f)
+        {
+            frames = start_frame;
+        }
+        else
+        {
+            switch (linear_animation->loop())
+            {
+                case Loop::oneShot:
+                {
+                    // Only the end the animation plays towards finishes it, like in
+                    // `LinearAnimationInstance::advance`.
+                    bool is_forwards = plays_forwards(linear_animation);
+
+                    if (is_forwards ? frames > end_frame : frames < start_frame)
+                    {
+                        frames = is_forwards ? end_frame : start_frame;
+                        keep_going = false;
+                        *did_loop = true;
+                    }
+                    else
+                    {
+                        frames = std::max(start_frame, std::min(frames, end_frame));
+                    }
+                    break;
+                }
+                case Loop::loop:
+                    if (frames >= end_frame || frames < start_frame)
+                    {
+                        frames = start_frame + std::fmod(frames - start_frame, range);
+                        if (frames < start_frame)
+                        {
+                            frames += range;
+                        }
+                        *did_loop = true;
+                    }
+                    break;
+                case Loop::pingPong:
+                {
+                    // Number of times the animation bounced off either end of the range.
+                    float bounces = std::floor((frames - start_frame) / range);
+                    float offset = frames - start_frame - bounces * range;
+
+                    if (bounces != 0.0f)
+                    {
+                        bool is_mirrored = std::fmod(std::abs(bounces), 2.0f) == 1.0f;
+
+                        frames = is_mirrored ? end_frame - offset : start_frame + offset;
+                        direction = is_mirrored ? -direction : direction;
+                        *did_loop = true;
+                    }
+                    break;
+                }
+            }
+        }
+
+        linear_animation->time(frames / fps);
+        linear_animation->direction(direction);
+
+        return keep_going;
+    }
+
+    // Like `LinearAnimationInstance::advanceAndApply`, but advancing with
+    // `rive_rs_linear_animation_advance_in`.
+    bool rive_rs_linear_animation_advance_and_apply_in(LinearAnimationInstance* linear_animation,
+                                                       ArtboardInstance* artboard_instance,
+                                                       float elapsed,
+                                                       uint32_t start,
+                                                       uint32_t end,
+                                                       bool* did_loop)
+    {
+        bool keep_going =
+            rive_rs_linear_animation_advance_in(linear_animation, elapsed, start, end, did_loop);
+
+        linear_animation->apply();
+
+        if (artboard_instance->advance(elapsed))
+        {
+            keep_going = true;
+        }
+
+        return keep_going;
+    }
+
+    void rive_rs_instantiate_state_machine(ArtboardInstance* artboard_instance,
+                                           const size_t* index,
+                                           StateMachineInstance** state_machine)
+    {
+
+        if (index)
+        {
+            if (*index < (size_t)artboard_instance->stateMachineCount())
+            {
+                *state_machine = artboard_instance->stateMachineAt(*index).release();
+            }
+        }
+        else
+        {
+            auto ptr = artboard_instance->defaultStateMachine();
+            if (ptr)
+            {
+                *state_machine = ptr.release();
+            }
+            else if (artboard_instance->stateMachineCount())
+            {
+                *state_machine = artboard_instance->stateMachineAt(0).release();
+            }
+        }
+    }
+
+    void rive_rs_instantiate_state_machine_by_name(ArtboardInstance* artboard_instance,
+                                                   const char* data,
+                                                   size_t len,
+                                                   StateMachineInstance** state_machine)
+    {
+        *state_machine = artboard_instance->stateMachineNamed({data, len}).release();
+    }
+
+    void rive_rs_state_machine_get_event(const StateMachineInstance* state_machine_instance,
+                                         size_t index,
+                                         Event** event,
+                                         float* delay)
+    {
+        auto event_report = state_machine_instance->reportedEventAt(index);
+        *event = event_report.event();
+        *delay = event_report.secondsDelay();
+    }
+
+    size_t rive_rs_state_machine_event_count(const StateMachineInstance* state_machine_instance)
+    {
+        return state_machine_instance->reportedEventCount();
+    }
+
+    size_t rive_rs_state_machine_state_changed_count(
+        const StateMachineInstance* state_machine_instance)
+    {
+        return state_machine_instance->stateChangedCount();
+    }
+
+    void rive_rs_state_machine_get_state_changed(const StateMachineInstance* state_machine_instance,
+                                                 size_t index,
+                                                 const RawRustString* layer_name,
+                                                 const RawRustString* state_name)
+    {
+        auto state = state_machine_instance->stateChangedByIndex(index);
+        auto state_machine = state_machine_instance->stateMachine();
+
+        for (size_t i = 0; i < state_machine->layerCount(); ++i)
+        {
+            auto layer = state_machine->layer(i);
+
+            for (size_t j = 0; j < layer->stateCount(); ++j)
+            {
+                if (layer->state(j) == state)
+                {
+                    rive_rs_allocate_string(layer_name, layer->name().data(), layer->name().size());
+                }
+            }
+        }
+
+        std::string name;
+        if (state->is<AnimationState>())
+        {
+            auto animation = state->as<AnimationState>()->animation();
+            name = animation != nullptr ? animation->name() : "";
+        }
+        else if (state->is<EntryState>())
+        {
+            name = "Entry";
+        }
+        else if (state->is<ExitState>())
+        {
+            name = "Exit";
+        }
+        else if (state->is<AnyState>())
+        {
+            name = "Any";
+        }
+
+        rive_rs_allocate_string(state_name, name.data(), name.size());
+    }
+
+    void rive_rs_event_name(const Event* event, const RawRustString* string)
+    {
+        rive_rs_allocate_string(string, event->name().data(), event->name().size());
+    }
+
+    bool rive_rs_event_open_url(const Event* event, const RawRustString* url, uint32_t* target)
+    {
+        if (!event->is<OpenUrlEvent>())
+        {
+            return false;
+        }
+
+        auto open_url_event = event->as<OpenUrlEvent>();
+        rive_rs_allocate_string(url, open_url_event->url().data(), open_url_event->url().size());
+        *target = open_url_event->targetValue();
+
+        return true;
+    }
+
+    void rive_rs_event_properties(const Event* event, const RawRustBTreeMap* properties)
+    {
+        for (auto child : event->children())
+        {
+            switch (child->coreType())
+            {
+                case CustomPropertyBoolean::typeKey:
+                {
+                    auto boolean = static_cast<CustomPropertyBoolean*>(child);
+
+                    Property property;
+                    property.boolean = boolean->propertyValue();
+
+                    rive_rs_insert_property(properties,
+                                            child->name().data(),
+                                            child->name().size(),
+                                            PropertyTag::Bool,
+                                            property);
+
+                    break;
+                }
+                case CustomPropertyNumber::typeKey:
+                {
+                    auto number = static_cast<CustomPropertyNumber*>(child);
+
+                    Property property;
+                    property.number = number->propertyValue();
+
+                    rive_rs_insert_property(properties,
+                                            child->name().data(),
+                                            child->name().size(),
+                                            PropertyTag::Number,
+                                            property);
+
+                    break;
+                }
+                case CustomPropertyString::typeKey:
+                {
+                    auto string = static_cast<CustomPropertyString*>(child);
+
+                    Property property;
+                    property.string = {string->propertyValue().data(),
+                                       string->propertyValue().size()};
+
+                    rive_rs_insert_property(properties,
+                                            child->name().data(),
+                                            child->name().size(),
+                                            PropertyTag::String,
+                                            property);
+
+                    break;
+                }
+            }
+        }
+    }
+
+    void rive_rs_state_machine_get_input(const StateMachineInstance* state_machine_instance,
+                                         size_t index,
+                                         InputTag* input_tag,
+                                         SMIInput** input)
+    {
+        *input = state_machine_instance->input(index);
+
+        if ((*input)->input()->is<StateMachineBool>())
+        {
+            *input_tag = InputTag::Bool;
+        }
+
+        if ((*input)->input()->is<StateMachineNumber>())
+        {
+            *input_tag = InputTag::Number;
+        }
+
+        if ((*input)->input()->is<StateMachineTrigger>())
+        {
+            *input_tag = InputTag::Trigger;
+        }
+    }
+
+    static bool is_disabled(const size_t* disabled, size_t disabled_len, size_t index)
+    {
+        return std::find(disabled, disabled + disabled_len, index) != disabled + disabled_len;
+    }
+
+    // Templates can't have C linkage.
+    extern "C++"
+    {
+        // Listeners hit-test every shape nested under their target. Calls `f` with each of them
+        // until it returns `true`.
+        template <typename F>
+        static bool any_listener_shape(ArtboardInstance* artboard_instance,
+                                       const StateMachineListener* listener,
+                                       F f)
+        {
+            auto target = artboard_instance->resolve(listener->targetId());
+            if (target == nullptr)
+            {
+                return false;
+            }
+
+            for (auto object : artboard_instance->objects())
+            {
+                if (object == nullptr || !object->is<Shape>())
+                {
+                    continue;
+                }
+
+                for (const Component* component = object->as<Shape>(); component != nullptr;
+                     component = component->parent())
+                {
+                    if (component == target)
+                    {
+                        if (f(object->as<Shape>()))
+                        {
+                            return true;
+                        }
+
+                        break;
+                    }
+                }
+            }
+
+            return false;
+        }
+    }
+
+    void rive_rs_state_machine_listener_bounds(const StateMachineInstance* state_machine_instance,
+                                               ArtboardInstance* artboard_instance,
+                                               const size_t* disabled,
+                                               size_t disabled_len,
+                                               const RawRustVec* points)
+    {
+        auto state_machine = state_machine_instance->stateMachine();
+
+        for (size_t i = 0; i < state_machine->listenerCount(); ++i)
+        {
+            if (is_disabled(disabled, disabled_len, i))
+            {
+                continue;
+            }
+
+            any_listener_shape(artboard_instance, state_machine->listener(i), [&](Shape* shape) {
+                push_world_bounds(shape, points);
+                return false;
+            });
+        }
+    }
+
+    size_t rive_rs_state_machine_listener_count(const StateMachineInstance* state_machine_instance)
+    {
+        return state_machine_instance->stateMachine()->listenerCount();
+    }
+
+    void rive_rs_state_machine_listener_name(const StateMachineInstance* state_machine_instance,
+                                             size_t index,
+                                             const char** data,
+                                             size_t* len)
+    {
+        auto listener = state_machine_instance->stateMachine()->listener(index);
+        *data = listener->name().data();
+        *len = listener->name().size();
+    }
+
+    enum class PointerEvent : uint8_t
+    {
+        Down,
+        Move,
+        Up,
+    };
+
+    // A shape hit-tested by the listeners of a state machine, with whether the pointer was over
+    // it during the last event.
+    struct HitShape
+    {
+        Shape* shape;
+        std::vector<size_t> listeners;
+        bool is_hovered;
+    };
+
+    // What pointer events are dispatched to for one state machine instance. Listeners belong to
+    // the file's state machine and are shared by all of its instances, so disabled listeners are
+    // skipped here instead of being changed, and hover state is kept per instance.
+    struct PointerTargets
+    {
+        std::vector<HitShape> shapes;
+        std::vector<NestedArtboard*> nested_artboards;
+    };
+
+    PointerTargets* rive_rs_pointer_targets_new(const StateMachineInstance* state_machine_instance,
+                                                ArtboardInstance* artboard_instance)
+    {
+        auto targets = new PointerTargets();
+        auto state_machine = state_machine_instance->stateMachine();
+
+        // Like `StateMachineInstance`, group the listeners by the shapes they hit-test, so that
+        // enter and exit listeners fire when the pointer moves between shapes.
+        for (size_t i = 0; i < state_machine->listenerCount(); ++i)
+        {
+            any_listener_shape(artboard_instance, state_machine->listener(i), [&](Shape* shape) {
+                auto hit_shape = std::find_if(targets->shapes.begin(),
+                                              targets->shapes.end(),
+                                              [&](const HitShape& hit_shape) {
+                                                  return hit_shape.shape == shape;
+                                              });
+
+                if (hit_shape == targets->shapes.end())
+                {
+                    targets->shapes.push_back({shape, {i}, false});
+                }
+                else
+                {
+                    hit_shape->listeners.push_back(i);
+                }
+
+                return false;
+            });
+        }
+
+        for (auto nested_artboard : artboard_instance->nestedArtboards())
+        {
+            for (auto nested_animation : nested_artboard->nestedAnimations())
+            {
+                if (nested_animation->is<NestedStateMachine>())
+                {
+                    targets->nested_artboards.push_back(nested_artboard);
+                    break;
+                }
+            }
+        }
+
+        return targets;
+    }
+
+    void rive_rs_pointer_targets_release(PointerTargets* targets) { delete targets; }
+
+    // Dispatches a pointer event the way `StateMachineInstance` does, skipping the `disabled`
+    // listeners. Nested artboards' state machines receive the event as usual.
+    void rive_rs_state_machine_pointer(StateMachineInstance* state_machine_instance,
+                                       ArtboardInstance* artboard_instance,
+                                       PointerTargets* targets,
+                                       PointerEvent event,
+                                       float x,
+                                       float y,
+                                       const size_t* disabled,
+                                       size_t disabled_len)
+    {
+        Vec2D position(x, y);
+        if (artboard_instance->frameOrigin())
+        {
+            position -= Vec2D(artboard_instance->originX() * artboard_instance->width(),
+                              artboard_instance->originY() * artboard_instance->height());
+        }
+
+        // Same hit area as the one `StateMachineInstance` uses.
+        const float hit_radius = 2.0f;
+        auto hit_area = AABB(position.x - hit_radius,
+                             position.y - hit_radius,
+                             position.x + hit_radius,
+                             position.y + hit_radius)
+                            .round();
+
+        ListenerType hit_type = ListenerType::move;
+        switch (event)
+        {
+            case PointerEvent::Down:
+                hit_type = ListenerType::down;
+                break;
+            case PointerEvent::Move:
+                hit_type = ListenerType::move;
+                break;
+            case PointerEvent::Up:
+                hit_type = ListenerType::up;
+                break;
+        }
+
+        auto state_machine = state_machine_instance->stateMachine();
+
+        for (auto& hit_shape : targets->shapes)
+        {
+            // Hover state is tracked even if all of the shape's listeners are disabled, so that
+            // enabling them again doesn't fire enter or exit listeners for stale state.
+            bool is_over = hit_shape.shape->hitTest(hit_area);
+            bool hover_changed = hit_shape.is_hovered != is_over;
+            hit_shape.is_hovered = is_over;
+
+            for (auto i : hit_shape.listeners)
+            {
+                if (is_disabled(disabled, disabled_len, i))
+                {
+                    continue;
+                }
+
+                auto listener = state_machine->listener(i);
+                auto listener_type = listener->listenerType();
+
+                bool is_hit = is_over && listener_type == hit_type;
+                if (hover_changed)
+                {
+                    is_hit |= is_over && listener_type == ListenerType::enter;
+                    is_hit |= !is_over && listener_type == ListenerType::exit;
+                }
+
+                if (is_hit)
+                {
+                    listener->performChanges(state_machine_instance, position);
+                }
+            }
+        }
+
+        for (auto nested_artboard : targets->nested_artboards)
+        {
+            Vec2D nested_position;
+            if (nested_artboard->isCollapsed() ||
+                !nested_artboard->worldToLocal(position, &nested_position))
+            {
+                continue;
+            }
+
+            for (auto nested_animation : nested_artboard->nestedAnimations())
+            {
+                if (!nested_animation->is<NestedStateMachine>())
+                {
+                    continue;
+                }
+
+                auto nested_state_machine = nested_animation->as<NestedStateMachine>();
+                switch (event)
+                {
+                    case PointerEvent::Down:
+                        nested_state_machine->pointerDown(nested_position);
+                        break;
+                    case PointerEvent::Move:
+                        nested_state_machine->pointerMove(nested_position);
+                        break;
+                    case PointerEvent::Up:
+                        nested_state_machine->pointerUp(nested_position);
+                        break;
+                }
+            }
+        }
+    }
+
+    size_t rive_rs_state_machine_input_count(const StateMachineInstance* state_machine_instance)
+    {
+        return state_machine_instance->inputCount();
+    }
+
+    const SMIBool* rive_rs_state_machine_get_bool(
+        const StateMachineInstance* state_machine_instance,
+        const char* name,
+        size_t len)
+    {
+        std::string n(name, len);
+
+        return state_machine_instance->getBool({name, len});
+    }
+
+    const SMINumber* rive_rs_state_machine_get_number(
+        const StateMachineInstance* state_machine_instance,
+        const char* name,
+        size_t len)
+    {
+        std::string n(name, len);
+
+        return state_machine_instance->getNumber({name, len});
+    }
+
+    const SMITrigger* rive_rs_state_machine_get_trigger(
+        const StateMachineInstance* state_machine_instance,
+        const char* name,
+        size_t len)
+    {
+        std::string n(name, len);
+
+        return state_machine_instance->getTrigger({name, len});
+    }
+
+    void rive_rs_input_name(const SMIInput* input, const char** data, size_t* len)
+    {
+        *data = input->name().data();
+        *len = input->name().size();
+    }
+
+    bool rive_rs_bool_get(const SMIBool* bool_) { return bool_->value(); }
+
+    void rive_rs_bool_set(SMIBool* bool_, bool val) { bool_->value(val); }
+
+    float rive_rs_number_get(const SMINumber* number) { return number->value(); }
+
+    void rive_rs_number_set(SMINumber* number, float val) { number->value(val); }
+
+    void rive_rs_trigger_fire(SMITrigger* trigger) { trigger->fire(); }
+
+    void rive_rs_scene_release(const Scene* scene)
+    {
+        std::unique_ptr<Scene> val(std::move(const_cast<Scene*>(scene)));
+    }
+
+    Command rive_rs_commands_next(RawPath::Iter* commands)
+    {
+        auto tuple = **commands;
+        ++*commands;
+        return {std::get<0>(tuple), std::get<1>(tuple)};
+    }
+
+    float rive_rs_scene_width(const Scene* scene) { return scene->width(); }
+
+    float rive_rs_scene_height(const Scene* scene) { return scene->height(); }
+
+    Loop rive_rs_scene_loop(const Scene* scene) { return scene->loop(); }
+
+    bool rive_rs_scene_is_translucent(const Scene* scene) { return scene->isTranslucent(); }
+
+    float rive_rs_scene_duration(const Scene* scene) { return scene->durationSeconds(); }
+
+    bool rive_rs_scene_advance_and_apply(Scene* scene, float elapsed)
+    {
+        return scene->advanceAndApply(elapsed);
+    }
+
+    void rive_rs_scene_draw(Scene* scene,
+                            const RawRustRenderer* renderer,
+                            const RendererEntries* entries)
+    {
+        RustRenderer rust_renderer(renderer, entries);
+        scene->draw(&rust_renderer);
+    }
+
+    void rive_rs_scene_pointer_down(Scene* scene, float x, float y) { scene->pointerDown({x, y}); }
+
+    void rive_rs_scene_pointer_move(Scene* scene, float x, float y) { scene->pointerMove({x, y}); }
+
+    void rive_rs_scene_pointer_up(Scene* scene, float x, float y) { scene->pointerUp({x, y}); }
+
+    void rive_rs_artboard_instance_transforms(const ArtboardInstance* artboard_instance,
+                                              uint32_t width,
+                                              uint32_t height,
+                                              float* view_transform,
+                                              float* inverse_view_transform)
+    {
+        auto view_transform_mat = rive::computeAlignment(rive::Fit::contain,
+                                                         rive::Alignment::center,
+                                                         rive::AABB(0, 0, width, height),
+                                                         artboard_instance->bounds());
+        auto inverse_view_transform_mat = view_transform_mat.invertOrIdentity();
+
+        std::copy(view_transform_mat.values(), view_transform_mat.values() + 6, view_transform);
+        std::copy(inverse_view_transform_mat.values(),
+                  inverse_view_transform_mat.values() + 6,
+                  inverse_view_transform);
+    }
+}
@@ -0,0 +1,70 @@
+//! [`IntoTransform`] implementations for the transform types of other crates.
+
+use crate::renderer::IntoTransform;
+
+#[cfg(feature = "kurbo")]
+impl IntoTransform for kurbo::Affine {
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        self.as_coeffs().map(|coeff| coeff as f32)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl IntoTransform for glam::Affine2 {
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        let [a, b, c, d] = self.matrix2.to_cols_array();
+        [a, b, c, d, self.translation.x, self.translation.y]
+    }
+}
+
+#[cfg(feature = "glam")]
+impl IntoTransform for glam::Mat3 {
+    /// Drops the last row, which is assumed to be `[0, 0, 1]`.
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        [
+            self.x_axis.x,
+            self.x_axis.y,
+            self.y_axis.x,
+            self.y_axis.y,
+            self.z_axis.x,
+            self.z_axis.y,
+        ]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoTransform for mint::ColumnMatrix2x3<f32> {
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        [self.x.x, self.x.y, self.y.x, self.y.y, self.z.x, self.z.y]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoTransform for mint::RowMatrix2x3<f32> {
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        [self.x.x, self.y.x, self.x.y, self.y.y, self.x.z, self.y.z]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoTransform for mint::ColumnMatrix3<f32> {
+    /// Drops the last row, which is assumed to be `[0, 0, 1]`.
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        [self.x.x, self.x.y, self.y.x, self.y.y, self.z.x, self.z.y]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl IntoTransform for mint::RowMatrix3<f32> {
+    /// Drops the last row, which is assumed to be `[0, 0, 1]`.
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        [self.x.x, self.y.x, self.x.y, self.y.y, self.x.z, self.y.z]
+    }
+}
@@ -1,19 +1,97 @@
-use alloc::{boxed::Box, collections::BTreeMap, string::String};
+#![no_std]
+
+//! Raw bindings to the [Rive] C++ runtime.
+//!
+//! This crate builds the vendored rive-cpp runtime and exposes its C interface together with
+//! the [`RendererEntries`] that let it draw through a Rust [`Renderer`]. Most users should depend
+//! on the safe `rive-rs` crate instead.
+//!
+//! [Rive]: https://rive.app
+
+extern crate alloc;
+
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
 use core::{
     ptr::{self, NonNull},
     slice,
 };
 
+#[cfg(any(feature = "glam", feature = "kurbo", feature = "mint"))]
+mod interop;
+pub mod path;
+pub mod renderer;
+
 use crate::{
-    linear_animation::Loop,
-    path::{self, FillRule, Point, Verb},
+    path::{FillRule, Point, Rect, Verb},
     renderer::{
         BlendMode, Buffer, BufferFlags, BufferType, Color, Gradient, Image, Paint, PaintStyle,
         Path, Renderer, StrokeCap, StrokeJoin,
     },
-    state_machine,
 };
 
+/// The rive-cpp commit the crate was built against, or `"unknown"` if it could not be determined
+/// at build time.
+pub const RIVE_CPP_COMMIT: &str = env!("RIVE_CPP_COMMIT");
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Loop {
+    /// Play until the duration or end of work area of the animation.
+    OneShot = 0,
+    /// Play until the duration or end of work area of the animation and
+    /// then go back to the start (0 seconds).
+    Loop = 1,
+    /// Play to the end of the duration/work area and then play back.
+    PingPong = 2,
+}
+
+/// A custom property attached to an event in the editor.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Property {
+    Bool(bool),
+    Number(f32),
+    String(String),
+}
+
+impl Property {
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Property::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_number(&self) -> Option<f32> {
+        match self {
+            Property::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Property::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Cache of decoded assets consulted by the [`RendererEntries`] while a file is being loaded.
+///
+/// It is passed to [`rive_rs_file_new`] as a pointer to a `&mut dyn AssetCache<R>`, or as null
+/// to decode every asset anew.
+pub trait AssetCache<R: Renderer> {
+    /// Returns the image decoded from `data`, decoding it if it is not cached yet.
+    fn image(&mut self, data: &[u8]) -> Option<Arc<R::Image>>;
+    /// Returns the cached font loaded from `data`, or null if there is none.
+    fn font(&self, data: &[u8]) -> *const Font;
+    /// Caches `font`, which has already been referenced for the cache.
+    fn insert_font(&mut self, data: &[u8], font: *const Font);
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 pub struct RawString {
@@ -50,12 +128,21 @@ pub enum File {}
 #[derive(Clone, Copy)]
 pub enum Artboard {}
 
+#[derive(Clone, Copy)]
+pub enum Font {}
+
 #[derive(Clone, Copy)]
 pub enum Component {}
 
 #[derive(Clone, Copy)]
 pub enum TextValueRun {}
 
+#[derive(Clone, Copy)]
+pub enum Solo {}
+
+#[derive(Clone, Copy)]
+pub enum PointsPath {}
+
 #[derive(Clone, Copy)]
 pub enum LinearAnimation {}
 
@@ -65,6 +152,9 @@ pub enum StateMachine {}
 #[derive(Clone, Copy)]
 pub enum Scene {}
 
+#[derive(Clone, Copy)]
+pub enum PointerTargets {}
+
 #[allow(dead_code)]
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
@@ -76,7 +166,7 @@ pub enum PropertyTag {
 
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub union Property {
+pub union PropertyPayload {
     bool: bool,
     number: f32,
     string: RawString,
@@ -88,6 +178,23 @@ pub enum Event {}
 #[derive(Clone, Copy)]
 pub enum Input {}
 
+#[allow(dead_code)]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug)]
+pub enum PointerEvent {
+    Down,
+    Move,
+    Up,
+}
+
+#[allow(dead_code)]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AssetTag {
+    Image,
+    Font,
+}
+
 #[allow(dead_code)]
 #[repr(u8)]
 #[derive(Clone, Copy, Debug)]
@@ -115,26 +222,88 @@ unsafe extern "C" fn rive_rs_allocate_string(string: *mut String, data: *const u
     }
 }
 
+#[no_mangle]
+unsafe extern "C" fn rive_rs_push_color(colors: *mut Vec<Color>, color: Color) {
+    (*colors).push(color);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rive_rs_push_component(
+    components: *mut Vec<*const Component>,
+    component: *const Component,
+) {
+    (*components).push(component);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rive_rs_push_point(points: *mut Vec<Point>, x: f32, y: f32) {
+    (*points).push(Point { x, y });
+}
+
+#[no_mangle]
+unsafe extern "C" fn rive_rs_push_visible_text(
+    texts: *mut Vec<(String, Rect)>,
+    data: *const u8,
+    len: usize,
+    bounds: *const [f32; 4],
+) {
+    let [min_x, min_y, max_x, max_y] = *bounds;
+
+    if let Ok(text) = String::from_utf8(slice::from_raw_parts(data, len).to_vec()) {
+        (*texts).push((
+            text,
+            Rect {
+                min: Point { x: min_x, y: min_y },
+                max: Point { x: max_x, y: max_y },
+            },
+        ));
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn rive_rs_push_text_line(
+    lines: *mut Vec<[f32; 4]>,
+    top: f32,
+    baseline: f32,
+    bottom: f32,
+    width: f32,
+) {
+    (*lines).push([top, baseline, bottom, width]);
+}
+
+#[no_mangle]
+unsafe extern "C" fn rive_rs_push_asset(
+    assets: *mut Vec<(AssetTag, String, bool)>,
+    tag: AssetTag,
+    data: *const u8,
+    len: usize,
+    is_loaded: bool,
+) {
+    let name = String::from_utf8_lossy(slice::from_raw_parts(data, len)).into_owned();
+
+    (*assets).push((tag, name, is_loaded));
+}
+
 #[no_mangle]
 unsafe extern "C" fn rive_rs_insert_property(
-    properties: *mut BTreeMap<String, state_machine::Property>,
+    properties: *mut BTreeMap<String, Property>,
     key_data: *const u8,
     key_len: usize,
     value_tag: PropertyTag,
-    value_payload: Property,
+    value_payload: PropertyPayload,
 ) {
     let properties = &mut *properties;
 
     if let Ok(key) = String::from_utf8(slice::from_raw_parts(key_data, key_len).to_vec()) {
         let value = match value_tag {
-            PropertyTag::Bool => state_machine::Property::Bool(value_payload.bool),
-            PropertyTag::Number => state_machine::Property::Number(value_payload.number),
+            PropertyTag::Bool => Property::Bool(value_payload.bool),
+            PropertyTag::Number => Property::Number(value_payload.number),
             PropertyTag::String => {
                 if let Ok(string) = String::from_utf8(
                     slice::from_raw_parts(value_payload.string.data, value_payload.string.len)
                         .to_vec(),
                 ) {
-                    state_machine::Property::String(string)
+                    Property::String(string)
                 } else {
                     return;
                 }
@@ -183,8 +352,11 @@ pub struct RendererEntries<R: Renderer> {
     gradient_new_radial:
         unsafe extern "C" fn(f32, f32, f32, *const Color, *const f32, usize) -> *mut R::Gradient,
     gradient_release: unsafe extern "C" fn(*mut R::Gradient),
-    image_decode: unsafe extern "C" fn(*const u8, usize) -> *mut R::Image,
-    image_release: unsafe extern "C" fn(*mut R::Image),
+    image_decode:
+        unsafe extern "C" fn(*mut &mut dyn AssetCache<R>, *const u8, usize) -> *const R::Image,
+    image_release: unsafe extern "C" fn(*const R::Image),
+    font_cached: unsafe extern "C" fn(*mut &mut dyn AssetCache<R>, *const u8, usize) -> *const Font,
+    font_insert: unsafe extern "C" fn(*mut &mut dyn AssetCache<R>, *const u8, usize, *const Font),
     renderer_state_push: unsafe extern "C" fn(*mut R),
     renderer_state_pop: unsafe extern "C" fn(*mut R),
     renderer_transform: unsafe extern "C" fn(*mut R, transform: *const [f32; 6]),
@@ -377,16 +549,42 @@ impl<R: Renderer> RendererEntries<R> {
         }
 
         unsafe extern "C" fn image_deocde<R: Renderer>(
+            cache: *mut &mut dyn AssetCache<R>,
             data: *const u8,
             len: usize,
-        ) -> *mut R::Image {
-            R::Image::decode(slice::from_raw_parts(data, len))
-                .map(|image| Box::into_raw(Box::new(image)))
-                .unwrap_or(ptr::null_mut())
+        ) -> *const R::Image {
+            let data = slice::from_raw_parts(data, len);
+
+            // Images are reference counted so that they can be shared through the cache.
+            match cache.as_mut() {
+                Some(cache) => cache.image(data),
+                None => R::Image::decode(data).map(Arc::new),
+            }
+            .map(Arc::into_raw)
+            .unwrap_or(ptr::null())
         }
 
-        unsafe extern "C" fn image_release<R: Renderer>(image: *mut R::Image) {
-            drop(Box::from_raw(image))
+        unsafe extern "C" fn image_release<R: Renderer>(image: *const R::Image) {
+            if !image.is_null() {
+                drop(Arc::from_raw(image))
+            }
+        }
+
+        unsafe extern "C" fn font_cached<R: Renderer>(
+            cache: *mut &mut dyn AssetCache<R>,
+            data: *const u8,
+            len: usize,
+        ) -> *const Font {
+            (*cache).font(slice::from_raw_parts(data, len))
+        }
+
+        unsafe extern "C" fn font_insert<R: Renderer>(
+            cache: *mut &mut dyn AssetCache<R>,
+            data: *const u8,
+            len: usize,
+            font: *const Font,
+        ) {
+            (*cache).insert_font(slice::from_raw_parts(data, len), font);
         }
 
         unsafe extern "C" fn renderer_state_push<R: Renderer>(renderer: *mut R) {
@@ -470,6 +668,8 @@ impl<R: Renderer> RendererEntries<R> {
             gradient_release: gradient_release::<R>,
             image_decode: image_deocde::<R>,
             image_release: image_release::<R>,
+            font_cached: font_cached::<R>,
+            font_insert: font_insert::<R>,
             renderer_state_push: renderer_state_push::<R>,
             renderer_state_pop: renderer_state_pop::<R>,
             renderer_transform: renderer_transform::<R>,
@@ -487,10 +687,24 @@ extern "C" {
         data: *const u8,
         len: usize,
         entries: *const (),
+        asset_cache: *mut (),
         result: *mut FileResult,
         factory: *mut *mut Factory,
     ) -> *const File;
     pub fn rive_rs_file_release(file: *const File, factory: *mut Factory);
+    pub fn rive_rs_font_release(font: *const Font);
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_file_assets(file: *const File, assets: *mut Vec<(AssetTag, String, bool)>);
+    pub fn rive_rs_property_field_id(property_key: u32) -> i32;
+    pub fn rive_rs_is_type_key_known(type_key: u32) -> bool;
+    pub fn rive_rs_file_major_version() -> u32;
+    pub fn rive_rs_file_minor_version() -> u32;
+    pub fn rive_rs_file_remap_colors(
+        file: *const File,
+        from: *const Color,
+        to: *const Color,
+        len: usize,
+    );
     pub fn rive_rs_instantiate_artboard(
         file: *const File,
         index: Option<NonNull<usize>>,
@@ -502,7 +716,15 @@ extern "C" {
         len: usize,
         raw_artboard: *mut Option<NonNull<Artboard>>,
     );
+    pub fn rive_rs_file_artboard_count(file: *const File) -> usize;
+    pub fn rive_rs_file_artboard_name(
+        file: *const File,
+        index: usize,
+        data: *mut *const u8,
+        len: *mut usize,
+    );
     pub fn rive_rs_artboard_instance_release(artboard_instance: *mut Artboard);
+    pub fn rive_rs_artboard_duplicate(artboard_instance: *const Artboard) -> *mut Artboard;
     pub fn rive_rs_artboard_component_count(artboard_instance: *mut Artboard) -> usize;
     pub fn rive_rs_artboard_get_component(
         artboard_instance: *mut Artboard,
@@ -511,12 +733,87 @@ extern "C" {
     pub fn rive_rs_artboard_width(artboard_instance: *const Artboard) -> f32;
     pub fn rive_rs_artboard_height(artboard_instance: *const Artboard) -> f32;
     pub fn rive_rs_artboard_advance(artboard_instance: *mut Artboard);
+    pub fn rive_rs_artboard_loop(artboard_instance: *const Artboard) -> Loop;
+    pub fn rive_rs_artboard_linear_animation_count(artboard_instance: *const Artboard) -> usize;
+    pub fn rive_rs_artboard_linear_animation_name(
+        artboard_instance: *const Artboard,
+        index: usize,
+        data: *mut *const u8,
+        len: *mut usize,
+    );
+    pub fn rive_rs_artboard_state_machine_count(artboard_instance: *const Artboard) -> usize;
+    pub fn rive_rs_artboard_state_machine_name(
+        artboard_instance: *const Artboard,
+        index: usize,
+        data: *mut *const u8,
+        len: *mut usize,
+    );
     pub fn rive_rs_artboard_draw(
         artboard_instance: *mut Artboard,
         renderer: *mut (),
         entries: *const (),
     );
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_artboard_draw_order(
+        artboard_instance: *mut Artboard,
+        drawables: *mut Vec<*const Component>,
+    );
+    pub fn rive_rs_artboard_bounds(artboard_instance: *const Artboard, bounds: *mut f32);
+    pub fn rive_rs_artboard_background_color(
+        artboard_instance: *const Artboard,
+        color: *mut Color,
+    ) -> bool;
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_artboard_shape_bounds(
+        artboard_instance: *const Artboard,
+        points: *mut Vec<Point>,
+    );
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_artboard_bones(artboard_instance: *const Artboard, points: *mut Vec<Point>);
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_artboard_visible_text(
+        artboard_instance: *mut Artboard,
+        texts: *mut Vec<(String, Rect)>,
+    );
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_artboard_measure_text_run(
+        artboard_instance: *mut Artboard,
+        data: *const u8,
+        len: usize,
+        bounds: *mut [f32; 4],
+        size: *mut [f32; 2],
+        lines: *mut Vec<[f32; 4]>,
+        run_bounds: *mut [f32; 4],
+        has_run_bounds: *mut bool,
+    ) -> bool;
+    pub fn rive_rs_artboard_set_color(
+        artboard_instance: *mut Artboard,
+        data: *const u8,
+        len: usize,
+        color: Color,
+    ) -> bool;
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_artboard_colors(artboard_instance: *const Artboard, colors: *mut Vec<Color>);
+    pub fn rive_rs_artboard_remap_colors(
+        artboard_instance: *mut Artboard,
+        from: *const Color,
+        to: *const Color,
+        len: usize,
+    );
+    pub fn rive_rs_artboard_set_gradient_stop_color(
+        artboard_instance: *mut Artboard,
+        data: *const u8,
+        len: usize,
+        index: usize,
+        color: Color,
+    ) -> bool;
     pub fn rive_rs_component_type_id(component: *const Component) -> u16;
+    pub fn rive_rs_component_nested_artboard(component: *mut Component) -> *mut Artboard;
+    pub fn rive_rs_component_parent(component: *const Component, parent: *mut u32) -> bool;
+    pub fn rive_rs_component_world_transform(
+        component: *const Component,
+        transform: *mut [f32; 6],
+    ) -> bool;
     pub fn rive_rs_component_name(
         component: *const Component,
         data: *mut *const u8,
@@ -532,6 +829,31 @@ extern "C" {
         data: *const u8,
         len: usize,
     );
+    pub fn rive_rs_solo_active_name(
+        solo: *const Solo,
+        data: *mut *const u8,
+        len: *mut usize,
+    ) -> bool;
+    pub fn rive_rs_solo_set_active(solo: *mut Solo, data: *const u8, len: usize) -> bool;
+    pub fn rive_rs_points_path_vertex_count(points_path: *const PointsPath) -> usize;
+    pub fn rive_rs_points_path_vertex(
+        points_path: *mut PointsPath,
+        index: usize,
+        points: *mut [f32; 6],
+    ) -> bool;
+    pub fn rive_rs_points_path_set_position(
+        points_path: *mut PointsPath,
+        index: usize,
+        x: f32,
+        y: f32,
+    );
+    pub fn rive_rs_points_path_set_control_point(
+        points_path: *mut PointsPath,
+        index: usize,
+        is_out: bool,
+        x: f32,
+        y: f32,
+    ) -> bool;
     pub fn rive_rs_instantiate_linear_animation(
         artboard: *mut Artboard,
         index: Option<NonNull<usize>>,
@@ -543,6 +865,10 @@ extern "C" {
         len: usize,
         linear_animation: *mut Option<NonNull<LinearAnimation>>,
     );
+    pub fn rive_rs_linear_animation_duplicate(
+        linear_animation: *const LinearAnimation,
+        artboard: *mut Artboard,
+    ) -> *mut LinearAnimation;
     pub fn rive_rs_linear_animation_time(linear_animation: *mut LinearAnimation) -> f32;
     pub fn rive_rs_linear_animation_set_time(linear_animation: *mut LinearAnimation, time: f32);
     pub fn rive_rs_linear_animation_is_forwards(linear_animation: *mut LinearAnimation) -> bool;
@@ -555,9 +881,42 @@ extern "C" {
         elapsed: f32,
     ) -> bool;
     pub fn rive_rs_linear_animation_apply(linear_animation: *mut LinearAnimation, mix: f32);
+    pub fn rive_rs_linear_animation_apply_at(
+        linear_animation: *mut LinearAnimation,
+        artboard_instance: *mut Artboard,
+        time: f32,
+        mix: f32,
+    );
     pub fn rive_rs_linear_animation_did_loop(linear_animation: *mut LinearAnimation) -> bool;
     pub fn rive_rs_linear_animation_set_loop(linear_animation: *mut LinearAnimation, r#loop: Loop);
     pub fn rive_rs_linear_animation_is_done(linear_animation: *mut LinearAnimation) -> bool;
+    pub fn rive_rs_linear_animation_fps(linear_animation: *mut LinearAnimation) -> u32;
+    pub fn rive_rs_linear_animation_duration(linear_animation: *mut LinearAnimation) -> u32;
+    pub fn rive_rs_linear_animation_work_area(
+        linear_animation: *mut LinearAnimation,
+        start: *mut u32,
+        end: *mut u32,
+    ) -> bool;
+    pub fn rive_rs_linear_animation_enter_range(
+        linear_animation: *mut LinearAnimation,
+        start: u32,
+        end: u32,
+    );
+    pub fn rive_rs_linear_animation_advance_in(
+        linear_animation: *mut LinearAnimation,
+        elapsed: f32,
+        start: u32,
+        end: u32,
+        did_loop: *mut bool,
+    ) -> bool;
+    pub fn rive_rs_linear_animation_advance_and_apply_in(
+        linear_animation: *mut LinearAnimation,
+        artboard_instance: *mut Artboard,
+        elapsed: f32,
+        start: u32,
+        end: u32,
+        did_loop: *mut bool,
+    ) -> bool;
     pub fn rive_rs_instantiate_state_machine(
         artboard: *mut Artboard,
         index: Option<NonNull<usize>>,
@@ -576,19 +935,56 @@ extern "C" {
         delay: *mut f32,
     );
     pub fn rive_rs_state_machine_event_count(state_machine: *mut StateMachine) -> usize;
+    pub fn rive_rs_state_machine_state_changed_count(state_machine: *mut StateMachine) -> usize;
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_state_machine_get_state_changed(
+        state_machine: *mut StateMachine,
+        index: usize,
+        layer_name: *mut String,
+        state_name: *mut String,
+    );
     #[allow(improper_ctypes)]
     pub fn rive_rs_event_name(event: *mut Event, string: *mut String);
     #[allow(improper_ctypes)]
-    pub fn rive_rs_event_properties(
-        event: *mut Event,
-        properties: *mut BTreeMap<String, state_machine::Property>,
-    );
+    pub fn rive_rs_event_open_url(event: *mut Event, url: *mut String, target: *mut u32) -> bool;
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_event_properties(event: *mut Event, properties: *mut BTreeMap<String, Property>);
     pub fn rive_rs_state_machine_get_input(
         state_machine: *mut StateMachine,
         index: usize,
         input_tag: *mut InputTag,
         input: *mut *mut Input,
     );
+    #[allow(improper_ctypes)]
+    pub fn rive_rs_state_machine_listener_bounds(
+        state_machine: *mut StateMachine,
+        artboard_instance: *mut Artboard,
+        disabled: *const usize,
+        disabled_len: usize,
+        points: *mut Vec<Point>,
+    );
+    pub fn rive_rs_state_machine_listener_count(state_machine: *mut StateMachine) -> usize;
+    pub fn rive_rs_state_machine_listener_name(
+        state_machine: *mut StateMachine,
+        index: usize,
+        data: *mut *const u8,
+        len: *mut usize,
+    );
+    pub fn rive_rs_pointer_targets_new(
+        state_machine: *mut StateMachine,
+        artboard_instance: *mut Artboard,
+    ) -> *mut PointerTargets;
+    pub fn rive_rs_pointer_targets_release(targets: *mut PointerTargets);
+    pub fn rive_rs_state_machine_pointer(
+        state_machine: *mut StateMachine,
+        artboard_instance: *mut Artboard,
+        targets: *mut PointerTargets,
+        event: PointerEvent,
+        x: f32,
+        y: f32,
+        disabled: *const usize,
+        disabled_len: usize,
+    );
     pub fn rive_rs_state_machine_input_count(state_machine: *mut StateMachine) -> usize;
     pub fn rive_rs_state_machine_get_bool(
         state_machine: *mut StateMachine,
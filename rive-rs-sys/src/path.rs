@@ -1,6 +1,6 @@
 use core::{fmt, slice};
 
-use crate::ffi;
+use crate::{rive_rs_commands_next, Command};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -9,6 +9,30 @@ pub struct Point {
     pub y: f32,
 }
 
+/// An axis-aligned rectangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum FillRule {
@@ -27,12 +51,12 @@ pub enum Verb {
 
 #[derive(Clone, Copy)]
 pub struct Commands {
-    raw_commands: *mut ffi::Commands,
+    raw_commands: *mut crate::Commands,
     len: usize,
 }
 
 impl Commands {
-    pub(crate) fn new(raw_commands: *mut ffi::Commands, len: usize) -> Self {
+    pub(crate) fn new(raw_commands: *mut crate::Commands, len: usize) -> Self {
         Self { raw_commands, len }
     }
 }
@@ -51,8 +75,7 @@ impl<'c> Iterator for &'c mut Commands {
         self.len.checked_sub(1).map(|new_len| {
             self.len = new_len;
 
-            let ffi::Command { verb, points } =
-                unsafe { ffi::rive_rs_commands_next(self.raw_commands) };
+            let Command { verb, points } = unsafe { rive_rs_commands_next(self.raw_commands) };
 
             match verb {
                 Verb::Move => (verb, unsafe { slice::from_raw_parts(points, 1) }),
@@ -0,0 +1,322 @@
+use alloc::{vec, vec::Vec};
+
+use bitflags::bitflags;
+
+use crate::path::{Commands, FillRule};
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BufferType {
+    Index,
+    Vertex,
+}
+
+bitflags! {
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct BufferFlags: u32 {
+        const NONE = 0;
+        const MAPPED_ONCE_AT_INITIALIZATION = 1;
+    }
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StrokeJoin {
+    Miter = 0,
+    Round = 1,
+    Bevel = 2,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StrokeCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BlendMode {
+    SrcOver = 3,
+    Screen = 14,
+    Overlay = 15,
+    Darken = 16,
+    Lighten = 17,
+    ColorDodge = 18,
+    ColorBurn = 19,
+    HardLight = 20,
+    SoftLight = 21,
+    Difference = 22,
+    Exclusion = 23,
+    Multiply = 24,
+    Hue = 25,
+    Saturation = 26,
+    Color = 27,
+    Luminosity = 28,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PaintStyle {
+    Stroke,
+    Fill,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Color {
+    pub b: u8,
+    pub g: u8,
+    pub r: u8,
+    pub a: u8,
+}
+
+pub trait Buffer: Sized {
+    fn new(r#type: BufferType, flags: BufferFlags, len_in_bytes: usize) -> Self;
+    fn map(&mut self) -> &mut [u8];
+    fn unmap(&mut self);
+}
+
+/// CPU-side buffer for back-ends that read vertices and indices directly from memory.
+impl Buffer for Vec<u8> {
+    #[inline]
+    fn new(_type: BufferType, _flags: BufferFlags, len_in_bytes: usize) -> Self {
+        vec![0; len_in_bytes]
+    }
+
+    #[inline]
+    fn map(&mut self) -> &mut [u8] {
+        self
+    }
+
+    #[inline]
+    fn unmap(&mut self) {}
+}
+
+pub trait Path: Default + Sized {
+    fn new(commands: &mut Commands, fill_rule: FillRule) -> Self;
+    fn reset(&mut self);
+    fn extend(&mut self, from: &Self, transform: &[f32; 6]);
+    fn set_fill_rule(&mut self, fill_rule: FillRule);
+    fn move_to(&mut self, x: f32, y: f32);
+    fn line_to(&mut self, x: f32, y: f32);
+    fn cubic_to(&mut self, ox: f32, oy: f32, ix: f32, iy: f32, x: f32, y: f32);
+    fn close(&mut self);
+}
+
+pub trait Paint: Default + Sized {
+    type Gradient: Gradient;
+
+    fn set_style(&mut self, style: PaintStyle);
+    fn set_color(&mut self, color: Color);
+    fn set_thickness(&mut self, thickness: f32);
+
+    /// Sets the stroke join. Ignored by default.
+    #[inline]
+    fn set_join(&mut self, join: StrokeJoin) {
+        let _ = join;
+    }
+
+    /// Sets the stroke cap. Ignored by default.
+    #[inline]
+    fn set_cap(&mut self, cap: StrokeCap) {
+        let _ = cap;
+    }
+
+    /// Sets the blend mode. Ignored by default, which draws everything with
+    /// [`BlendMode::SrcOver`].
+    #[inline]
+    fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        let _ = blend_mode;
+    }
+
+    /// Replaces the color with a gradient. Ignored by default, which keeps the last color set.
+    #[inline]
+    fn set_gradient(&mut self, gradient: &Self::Gradient) {
+        let _ = gradient;
+    }
+
+    /// Notifies that the stroked outline of paths drawn with this paint needs to be recomputed.
+    #[inline]
+    fn invalidate_stroke(&mut self) {}
+}
+
+pub trait Gradient: Sized {
+    fn new_linear(sx: f32, sy: f32, ex: f32, ey: f32, colors: &[Color], stops: &[f32]) -> Self;
+    fn new_radial(cx: f32, cy: f32, radius: f32, colors: &[Color], stops: &[f32]) -> Self;
+}
+
+/// Placeholder for back-ends that don't support gradients.
+impl Gradient for () {
+    #[inline]
+    fn new_linear(_sx: f32, _sy: f32, _ex: f32, _ey: f32, _colors: &[Color], _stops: &[f32]) {}
+
+    #[inline]
+    fn new_radial(_cx: f32, _cy: f32, _radius: f32, _colors: &[Color], _stops: &[f32]) {}
+}
+
+pub trait Image: Sized {
+    fn decode(data: &[u8]) -> Option<Self>;
+}
+
+/// Placeholder for back-ends that don't support images.
+impl Image for () {
+    #[inline]
+    fn decode(_data: &[u8]) -> Option<Self> {
+        Some(())
+    }
+}
+
+/// Conversion into the `[a, b, c, d, e, f]` affine transforms taken by [`Renderer::transform`].
+///
+/// The coefficients are the first two rows of a 3x3 matrix in column-major order, so that
+/// `(x, y)` is mapped to `(a * x + c * y + e, b * x + d * y + f)`. Besides arrays, it is
+/// implemented for the transform types of `kurbo`, `glam`, and `mint` behind features of the same
+/// name.
+pub trait IntoTransform {
+    fn into_transform(self) -> [f32; 6];
+}
+
+impl IntoTransform for [f32; 6] {
+    #[inline]
+    fn into_transform(self) -> [f32; 6] {
+        self
+    }
+}
+
+/// A render back-end.
+///
+/// Only state, transform, and path drawing operations are required. Clipping and image drawing
+/// default to no-ops, and back-ends that don't support gradients or images can use `()` as their
+/// [`Gradient`] and [`Image`] types and `Vec<u8>` as their [`Buffer`].
+pub trait Renderer: Sized + 'static {
+    type Buffer: Buffer;
+    type Path: Path;
+    type Paint: Paint<Gradient = Self::Gradient>;
+    type Gradient: Gradient;
+    type Image: Image;
+
+    fn state_push(&mut self);
+    fn state_pop(&mut self);
+    fn transform(&mut self, transform: &[f32; 6]);
+    fn draw_path(&mut self, path: &Self::Path, paint: &Self::Paint);
+
+    /// Same as [`Renderer::transform`], but takes any [`IntoTransform`], e.g. a `kurbo::Affine`.
+    #[inline]
+    fn transform_by(&mut self, transform: impl IntoTransform) {
+        self.transform(&transform.into_transform());
+    }
+
+    /// Clips everything drawn until the matching [`Renderer::state_pop`] to `path`. Ignored by
+    /// default.
+    #[inline]
+    fn set_clip(&mut self, path: &Self::Path) {
+        let _ = path;
+    }
+
+    /// Draws `image` at the origin of the current transform. Ignored by default.
+    #[inline]
+    fn draw_image(&mut self, image: &Self::Image, blend_mode: BlendMode, opacity: f32) {
+        let _ = (image, blend_mode, opacity);
+    }
+
+    /// Draws the triangles of `image` described by `vertices`, `uvs`, and `indices`. Ignored by
+    /// default.
+    #[inline]
+    fn draw_image_mesh(
+        &mut self,
+        image: &Self::Image,
+        vertices: &Self::Buffer,
+        uvs: &Self::Buffer,
+        indices: &Self::Buffer,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        let _ = (image, vertices, uvs, indices, blend_mode, opacity);
+    }
+
+    /// Starts compositing everything drawn until the matching [`Renderer::pop_layer`] into a
+    /// layer covering the `width` by `height` rectangle at the origin of the current transform.
+    ///
+    /// The layer is multiplied by `tint`, if any, and blended with `opacity`. The default
+    /// implementation draws straight through, ignoring both.
+    #[inline]
+    fn push_layer(&mut self, width: f32, height: f32, opacity: f32, tint: Option<Color>) {
+        let _ = (width, height, opacity, tint);
+    }
+
+    /// Finishes the layer started by the last [`Renderer::push_layer`].
+    #[inline]
+    fn pop_layer(&mut self) {}
+}
+
+/// Placeholder path that discards all commands, used by [`NullRenderer`].
+impl Path for () {
+    #[inline]
+    fn new(_commands: &mut Commands, _fill_rule: FillRule) -> Self {}
+
+    #[inline]
+    fn reset(&mut self) {}
+
+    #[inline]
+    fn extend(&mut self, _from: &Self, _transform: &[f32; 6]) {}
+
+    #[inline]
+    fn set_fill_rule(&mut self, _fill_rule: FillRule) {}
+
+    #[inline]
+    fn move_to(&mut self, _x: f32, _y: f32) {}
+
+    #[inline]
+    fn line_to(&mut self, _x: f32, _y: f32) {}
+
+    #[inline]
+    fn cubic_to(&mut self, _ox: f32, _oy: f32, _ix: f32, _iy: f32, _x: f32, _y: f32) {}
+
+    #[inline]
+    fn close(&mut self) {}
+}
+
+/// Placeholder paint that discards all settings, used by [`NullRenderer`].
+impl Paint for () {
+    type Gradient = ();
+
+    #[inline]
+    fn set_style(&mut self, _style: PaintStyle) {}
+
+    #[inline]
+    fn set_color(&mut self, _color: Color) {}
+
+    #[inline]
+    fn set_thickness(&mut self, _thickness: f32) {}
+}
+
+/// Renderer that discards all draw calls.
+///
+/// It makes it possible to advance scenes, collect events, and run hit tests without any
+/// rendering cost, e.g. on servers or in simulations. Images are decoded to `()` without reading
+/// their data.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullRenderer;
+
+impl Renderer for NullRenderer {
+    type Buffer = Vec<u8>;
+    type Path = ();
+    type Paint = ();
+    type Gradient = ();
+    type Image = ();
+
+    #[inline]
+    fn state_push(&mut self) {}
+
+    #[inline]
+    fn state_pop(&mut self) {}
+
+    #[inline]
+    fn transform(&mut self, _transform: &[f32; 6]) {}
+
+    #[inline]
+    fn draw_path(&mut self, _path: &Self::Path, _paint: &Self::Paint) {}
+}
@@ -1,14 +1,36 @@
-use core::{marker::PhantomData, ptr, slice, str};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData, ptr, slice, str};
 
 use crate::{
     ffi,
     raw_iter::{impl_iter, Raw},
 };
 
+mod points_path;
+mod solo;
 mod text_value_run;
 
+pub use points_path::{PointsPath, Vertex};
+pub use solo::Solo;
 pub use text_value_run::TextValueRun;
 
+/// Error returned when converting a [`Component`] into a component type it is not an instance
+/// of.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WrongTypeError {
+    /// The core type ID of the component.
+    pub type_id: u16,
+}
+
+impl fmt::Display for WrongTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "component has unexpected type ID {}", self.type_id)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WrongTypeError {}
+
 pub struct Component<'a> {
     raw_component: *mut ffi::Component,
     _phantom: PhantomData<&'a ()>,
@@ -30,6 +52,12 @@ impl Component<'_> {
 
         str::from_utf8(bytes).expect("component name is invalid UTF-8")
     }
+
+    /// Returns the instance of the artboard nested by the component, or null if it isn't a
+    /// nested artboard.
+    fn nested_artboard(&self) -> *mut ffi::Artboard {
+        unsafe { ffi::rive_rs_component_nested_artboard(self.raw_component) }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -52,22 +80,112 @@ impl Raw for RawArtboard {
 
 impl_iter!(Components, Component, RawArtboard, 'a);
 
+impl<'a> Components<'a> {
+    /// Returns the first component named `name`.
+    #[inline]
+    pub fn find_by_name(mut self, name: &str) -> Option<Component<'a>> {
+        self.find(|component| component.name() == name)
+    }
+
+    /// Returns the component at the slash-separated `path` as a `T`, e.g. [`TextValueRun`] or
+    /// [`Component`] itself.
+    ///
+    /// Every segment but the last names a nested artboard, which is searched for the next
+    /// segment, so `"Menu/Button/Label"` finds `Label` inside the nested artboard `Button`
+    /// inside the nested artboard `Menu`. This tells apart components that share their name
+    /// across the artboards a file is built from. Returns `None` if any segment is missing or
+    /// the component is not a `T`.
+    pub fn find_by_path<T: TryFrom<Component<'a>>>(self, path: &str) -> Option<T> {
+        let mut components = self;
+        let mut segments = path.split('/');
+        let name = segments.next_back()?;
+
+        for segment in segments {
+            let component = components.find(|component| {
+                component.name() == segment && !component.nested_artboard().is_null()
+            })?;
+
+            components = Components::new(RawArtboard(component.nested_artboard()));
+        }
+
+        T::try_from(components.find_by_name(name)?).ok()
+    }
+
+    /// Returns only the components of type `T`, e.g. [`TextValueRun`].
+    #[inline]
+    pub fn of_type<T: TryFrom<Component<'a>>>(self) -> impl Iterator<Item = T> + 'a {
+        self.filter_map(|component| T::try_from(component).ok())
+    }
+
+    /// Returns only the components whose name starts with `prefix`.
+    #[inline]
+    pub fn with_prefix<'p>(self, prefix: &'p str) -> impl Iterator<Item = Component<'a>> + 'p
+    where
+        'a: 'p,
+    {
+        self.filter(move |component| component.name().starts_with(prefix))
+    }
+
+    /// Returns only the components whose name matches the glob `pattern`, where `*` matches any
+    /// sequence of characters and `?` matches any single character.
+    #[inline]
+    pub fn matching<'p>(self, pattern: &'p str) -> impl Iterator<Item = Component<'a>> + 'p
+    where
+        'a: 'p,
+    {
+        self.filter(move |component| glob_matches(pattern, component.name()))
+    }
+}
+
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let mut p = 0;
+    let mut n = 0;
+    // Position of the last `*` in the pattern and of the name character it is matched up to.
+    let mut backtrack = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    backtrack = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 macro_rules! try_from_component {
     ( $component:ident, $raw_name:ident, $type_id:expr ) => {
         impl<'a> TryFrom<crate::artboard::components::Component<'a>> for $component<'a> {
-            type Error = ();
+            type Error = crate::artboard::components::WrongTypeError;
 
             fn try_from(
                 value: crate::artboard::components::Component<'a>,
             ) -> Result<Self, Self::Error> {
-                unsafe {
-                    (crate::ffi::rive_rs_component_type_id(value.raw_component) == $type_id)
-                        .then(|| Self {
-                            $raw_name: value.raw_component as *mut crate::ffi::$component,
-                            _phantom: ::core::marker::PhantomData,
-                        })
-                        .ok_or(())
-                }
+                let type_id = unsafe { crate::ffi::rive_rs_component_type_id(value.raw_component) };
+
+                (type_id == $type_id)
+                    .then(|| Self {
+                        $raw_name: value.raw_component as *mut crate::ffi::$component,
+                        _phantom: ::core::marker::PhantomData,
+                    })
+                    .ok_or(crate::artboard::components::WrongTypeError { type_id })
             }
         }
     };
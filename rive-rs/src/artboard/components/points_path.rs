@@ -0,0 +1,106 @@
+use core::marker::PhantomData;
+
+use crate::{ffi, path::Point};
+
+use super::try_from_component;
+
+/// A vertex of a [`PointsPath`], in the local coordinates of the path.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vertex {
+    pub position: Point,
+    /// The in and out control points of cubic vertices, or `None` for straight ones.
+    pub control_points: Option<(Point, Point)>,
+}
+
+/// A path drawn with the pen tool, whose vertices can be moved at runtime, e.g. to drive a blob
+/// or an audio visualizer from data every frame.
+///
+/// Edits mark the path dirty, so they show up the next time the artboard is advanced and drawn.
+/// Animations that key the same vertices overwrite them when they are applied. Parametric shapes
+/// like rectangles and ellipses regenerate their vertices and are not points paths.
+pub struct PointsPath<'a> {
+    raw_points_path: *mut ffi::PointsPath,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl PointsPath<'_> {
+    #[inline]
+    pub fn vertex_count(&self) -> usize {
+        unsafe { ffi::rive_rs_points_path_vertex_count(self.raw_points_path) }
+    }
+
+    pub fn vertex(&self, index: usize) -> Option<Vertex> {
+        if index >= self.vertex_count() {
+            return None;
+        }
+
+        let mut points = [0.0; 6];
+        let is_cubic =
+            unsafe { ffi::rive_rs_points_path_vertex(self.raw_points_path, index, &mut points) };
+
+        let point = |i: usize| Point {
+            x: points[i],
+            y: points[i + 1],
+        };
+
+        Some(Vertex {
+            position: point(0),
+            control_points: is_cubic.then(|| (point(2), point(4))),
+        })
+    }
+
+    #[inline]
+    pub fn vertices(&self) -> impl Iterator<Item = Vertex> + '_ {
+        (0..self.vertex_count()).filter_map(|index| self.vertex(index))
+    }
+
+    /// Moves the vertex at `index` to `position`. The control points of cubic vertices move along
+    /// with it. Returns `false` if there is no such vertex.
+    pub fn set_position(&mut self, index: usize, position: Point) -> bool {
+        if index >= self.vertex_count() {
+            return false;
+        }
+
+        unsafe {
+            ffi::rive_rs_points_path_set_position(
+                self.raw_points_path,
+                index,
+                position.x,
+                position.y,
+            );
+        }
+
+        true
+    }
+
+    /// Moves the in control point of the vertex at `index` to `point`. Mirrored and asymmetric
+    /// vertices keep their out control point aligned with it. Returns `false` if there is no such
+    /// vertex or it is straight.
+    #[inline]
+    pub fn set_in_point(&mut self, index: usize, point: Point) -> bool {
+        self.set_control_point(index, false, point)
+    }
+
+    /// Moves the out control point of the vertex at `index` to `point`. Mirrored and asymmetric
+    /// vertices keep their in control point aligned with it. Returns `false` if there is no such
+    /// vertex or it is straight.
+    #[inline]
+    pub fn set_out_point(&mut self, index: usize, point: Point) -> bool {
+        self.set_control_point(index, true, point)
+    }
+
+    fn set_control_point(&mut self, index: usize, is_out: bool, point: Point) -> bool {
+        index < self.vertex_count()
+            && unsafe {
+                ffi::rive_rs_points_path_set_control_point(
+                    self.raw_points_path,
+                    index,
+                    is_out,
+                    point.x,
+                    point.y,
+                )
+            }
+    }
+}
+
+try_from_component!(PointsPath, raw_points_path, 16);
@@ -0,0 +1,38 @@
+use core::{marker::PhantomData, ptr, slice, str};
+
+use crate::ffi;
+
+use super::try_from_component;
+
+/// A group that only shows one of its children at a time, e.g. to pick a character skin.
+pub struct Solo<'a> {
+    raw_solo: *mut ffi::Solo,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl Solo<'_> {
+    /// Returns the name of the child that is currently shown.
+    pub fn active(&self) -> Option<&str> {
+        let mut data = ptr::null();
+        let mut len = 0;
+
+        let bytes = unsafe {
+            ffi::rive_rs_solo_active_name(
+                self.raw_solo,
+                &mut data as *mut *const u8,
+                &mut len as *mut usize,
+            )
+            .then(|| slice::from_raw_parts(data, len))
+        }?;
+
+        Some(str::from_utf8(bytes).expect("component name is invalid UTF-8"))
+    }
+
+    /// Shows the child named `name` instead of the current one. Returns `false` if there is no
+    /// such child.
+    pub fn set_active(&mut self, name: &str) -> bool {
+        unsafe { ffi::rive_rs_solo_set_active(self.raw_solo, name.as_ptr(), name.len()) }
+    }
+}
+
+try_from_component!(Solo, raw_solo, 147);
@@ -0,0 +1,158 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{
+    fmt::{self, Write},
+    ptr, slice, str,
+};
+
+use crate::{ffi, renderer::Renderer, scene::Scene};
+
+use super::Artboard;
+
+fn write_str(out: &mut String, s: &str) -> fmt::Result {
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+
+    Ok(())
+}
+
+fn write_f32(out: &mut String, value: f32) -> fmt::Result {
+    if value.is_finite() {
+        write!(out, "{value}")
+    } else {
+        out.push_str("null");
+        Ok(())
+    }
+}
+
+fn component_name<'a>(raw_component: *const ffi::Component) -> &'a str {
+    let mut data = ptr::null();
+    let mut len = 0;
+
+    let bytes = unsafe {
+        ffi::rive_rs_component_name(
+            raw_component,
+            &mut data as *mut *const u8,
+            &mut len as *mut usize,
+        );
+        slice::from_raw_parts(data, len)
+    };
+
+    str::from_utf8(bytes).expect("component name is invalid UTF-8")
+}
+
+impl<R: Renderer> Artboard<R> {
+    /// Returns a JSON description of the artboard's components, meant for debugging files that
+    /// render differently than in the editor.
+    ///
+    /// Every component is listed with its `index` in the file, core `type_id`, `name`, `parent`
+    /// index, `world_transform` as `[xx, xy, yx, yy, tx, ty]`, and its position in the
+    /// `draw_order`. Fields that don't apply to a component are `null`. Objects the runtime
+    /// skipped when loading the file are left out.
+    ///
+    /// The format is not stable and is only meant to be read by humans or diffed.
+    pub fn debug_dump(&self) -> String {
+        let mut out = String::new();
+        self.write_debug_dump(&mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    fn write_debug_dump(&self, out: &mut String) -> fmt::Result {
+        let raw_artboard = self.inner.raw_artboard;
+
+        let mut drawables = Vec::new();
+        unsafe {
+            ffi::rive_rs_artboard_draw_order(
+                raw_artboard,
+                &mut drawables as *mut Vec<*const ffi::Component>,
+            );
+        }
+        let draw_order: BTreeMap<_, _> = drawables
+            .into_iter()
+            .enumerate()
+            .map(|(order, drawable)| (drawable, order))
+            .collect();
+
+        out.push_str("{\"name\":");
+        write_str(out, self.name())?;
+        out.push_str(",\"width\":");
+        write_f32(out, self.width())?;
+        out.push_str(",\"height\":");
+        write_f32(out, self.height())?;
+        out.push_str(",\"components\":[");
+
+        let count = unsafe { ffi::rive_rs_artboard_component_count(raw_artboard) };
+        let mut is_first = true;
+
+        for index in 0..count {
+            let raw_component = unsafe { ffi::rive_rs_artboard_get_component(raw_artboard, index) }
+                as *const ffi::Component;
+
+            if raw_component.is_null() {
+                continue;
+            }
+
+            if !is_first {
+                out.push(',');
+            }
+            is_first = false;
+
+            let type_id = unsafe { ffi::rive_rs_component_type_id(raw_component) };
+            write!(out, "{{\"index\":{index},\"type_id\":{type_id},\"name\":")?;
+            write_str(out, component_name(raw_component))?;
+
+            out.push_str(",\"parent\":");
+            let mut parent = 0u32;
+            if unsafe { ffi::rive_rs_component_parent(raw_component, &mut parent as *mut u32) } {
+                write!(out, "{parent}")?;
+            } else {
+                out.push_str("null");
+            }
+
+            out.push_str(",\"world_transform\":");
+            let mut transform = [0.0; 6];
+            if unsafe {
+                ffi::rive_rs_component_world_transform(
+                    raw_component,
+                    &mut transform as *mut [f32; 6],
+                )
+            } {
+                out.push('[');
+                for (i, &value) in transform.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_f32(out, value)?;
+                }
+                out.push(']');
+            } else {
+                out.push_str("null");
+            }
+
+            out.push_str(",\"draw_order\":");
+            match draw_order.get(&raw_component) {
+                Some(order) => write!(out, "{order}")?,
+                None => out.push_str("null"),
+            }
+
+            out.push('}');
+        }
+
+        out.push_str("]}");
+
+        Ok(())
+    }
+}
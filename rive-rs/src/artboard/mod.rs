@@ -1,6 +1,7 @@
-use alloc::sync::Arc;
+use alloc::{borrow::ToOwned, string::String, sync::Arc, vec::Vec};
 use core::{
     fmt,
+    iter::FusedIterator,
     marker::PhantomData,
     ptr::{self, NonNull},
     slice, str,
@@ -11,14 +12,65 @@ use crate::{
     ffi,
     file::{File, FileInner},
     instantiate::{Handle, Instantiate},
-    linear_animation::Loop,
-    renderer::Renderer,
-    scene::{Scene, Viewport},
+    linear_animation::{LinearAnimation, Loop},
+    path::{Point, Rect},
+    renderer::{Color, Renderer},
+    scene::{Scene, SceneKind},
+    state_machine::StateMachine,
+    theme::Theme,
+    trace::span,
 };
 
 use self::components::Components;
 
 pub mod components;
+mod debug;
+
+/// A visible text object of an artboard, as returned by [`Artboard::visible_text`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VisibleText {
+    /// The text of all the runs of the text object, concatenated.
+    pub text: String,
+    /// The world bounds of the text object in artboard coordinates.
+    pub bounds: Rect,
+}
+
+/// The layout of a text run and of the text object containing it, as returned by
+/// [`Artboard::measure_text_run`].
+///
+/// Lines and run bounds are in the text object's layout coordinates, where the first line starts
+/// at the top-left corner of the laid-out text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextMetrics {
+    /// The world bounds of the text object in artboard coordinates.
+    pub bounds: Rect,
+    /// The width of the laid-out text in the text object's own coordinates.
+    pub width: f32,
+    /// The height of the laid-out text in the text object's own coordinates.
+    pub height: f32,
+    /// The lines of the text object, from top to bottom.
+    pub lines: Vec<LineMetrics>,
+    /// The bounds of the run's own glyphs, or `None` if none of them were laid out, e.g. because
+    /// the run is empty or the crate was built without the `text` feature.
+    pub run_bounds: Option<Rect>,
+}
+
+/// A laid-out line of a text object, as part of [`TextMetrics`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineMetrics {
+    pub top: f32,
+    pub baseline: f32,
+    pub bottom: f32,
+    /// The advance width of the glyphs on the line.
+    pub width: f32,
+}
+
+impl LineMetrics {
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.bottom - self.top
+    }
+}
 
 #[derive(Debug)]
 pub(crate) struct ArtboardInner {
@@ -54,10 +106,347 @@ impl<R: Renderer> Artboard<R> {
         &self.inner
     }
 
+    /// Takes ownership of a `rive::ArtboardInstance` instantiated from `file`.
+    ///
+    /// # Safety
+    ///
+    /// `raw_artboard` must be a valid, heap-allocated artboard instance of `file`'s `rive::File`
+    /// which is not owned by anything else. It is deleted when the returned artboard and all the
+    /// scenes instantiated from it are dropped.
+    #[inline]
+    pub unsafe fn from_raw(file: &File<R>, raw_artboard: *mut ffi::Artboard) -> Self {
+        Self::from_inner(Arc::new(ArtboardInner {
+            _file: file.as_inner().clone(),
+            raw_artboard,
+        }))
+    }
+
+    /// Returns the underlying `rive::ArtboardInstance`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid for as long as `self` or any of the scenes instantiated from it
+    /// are alive. It must not be released.
+    #[inline]
+    pub unsafe fn as_raw(&self) -> *mut ffi::Artboard {
+        self.inner.raw_artboard
+    }
+
+    /// Creates a new instance of the artboard with the current state of all its components.
+    ///
+    /// Unlike instantiating the artboard again from its [`File`], the duplicate starts out looking
+    /// exactly like `self` does now, which makes it possible to spawn copies mid-animation. Scenes
+    /// instantiated from `self` are not duplicated; see [`LinearAnimation::duplicate`] to also
+    /// keep an animation's time.
+    pub fn duplicate(&self) -> Self {
+        span!("Artboard::duplicate");
+
+        Self::from_inner(Arc::new(ArtboardInner {
+            _file: self.inner._file.clone(),
+            raw_artboard: unsafe { ffi::rive_rs_artboard_duplicate(self.inner.raw_artboard) },
+        }))
+    }
+
+    /// Updates the artboard's components after animations have been applied to it, e.g. with
+    /// [`LinearAnimation::apply`].
+    #[inline]
+    pub fn advance(&mut self) {
+        unsafe {
+            ffi::rive_rs_artboard_advance(self.inner.raw_artboard);
+        }
+    }
+
+    /// Returns the artboard's rectangle in the world coordinates its components are laid out in,
+    /// e.g. the ones of [`VisibleText::bounds`].
+    ///
+    /// Unlike [`Scene::width`] and [`Scene::height`], this accounts for the artboard's origin:
+    /// an artboard whose origin is set to its center spans from minus half its size to plus half
+    /// its size. Pointer positions in artboard space are relative to the top-left corner of this
+    /// rectangle instead.
+    #[inline]
+    pub fn bounds(&self) -> Rect {
+        let mut bounds = [0.0; 4];
+        unsafe {
+            ffi::rive_rs_artboard_bounds(self.inner.raw_artboard, bounds.as_mut_ptr());
+        }
+
+        Rect {
+            min: Point {
+                x: bounds[0],
+                y: bounds[1],
+            },
+            max: Point {
+                x: bounds[2],
+                y: bounds[3],
+            },
+        }
+    }
+
     #[inline]
     pub fn components(&mut self) -> Components {
         Components::new(components::RawArtboard(self.inner.raw_artboard))
     }
+
+    /// Overrides the solid color of the fills and strokes of every component named `name`.
+    ///
+    /// `name` can either refer to a fill or stroke directly or to a shape, in which case all of its
+    /// fills and strokes are recolored. Returns `false` if no solid color paint was found.
+    ///
+    /// Animations keying the same color will override it when applied.
+    #[inline]
+    pub fn set_color(&mut self, name: &str, color: Color) -> bool {
+        unsafe {
+            ffi::rive_rs_artboard_set_color(
+                self.inner.raw_artboard,
+                name.as_ptr(),
+                name.len(),
+                color,
+            )
+        }
+    }
+
+    /// Overrides the color of the `index`-th gradient stop, in authoring order, of the fills and
+    /// strokes of every component named `name`.
+    ///
+    /// `name` is resolved the same way as in [`Artboard::set_color`]. The new color is visible
+    /// after the next advance. Returns `false` if no gradient with enough stops was found.
+    #[inline]
+    pub fn set_gradient_stop_color(&mut self, name: &str, index: usize, color: Color) -> bool {
+        unsafe {
+            ffi::rive_rs_artboard_set_gradient_stop_color(
+                self.inner.raw_artboard,
+                name.as_ptr(),
+                name.len(),
+                index,
+                color,
+            )
+        }
+    }
+
+    /// Returns the distinct colors of all the solid colors and gradient stops of the artboard, in
+    /// the order they first appear.
+    pub fn palette(&self) -> Vec<Color> {
+        let mut colors = Vec::new();
+        unsafe {
+            ffi::rive_rs_artboard_colors(self.inner.raw_artboard, &mut colors as *mut Vec<Color>);
+        }
+
+        let mut palette: Vec<Color> = Vec::with_capacity(colors.len());
+        for color in colors {
+            if !palette.contains(&color) {
+                palette.push(color);
+            }
+        }
+
+        palette
+    }
+
+    /// Recolors this artboard instance with `theme`.
+    ///
+    /// Use [`File::apply_theme`] to also recolor artboards instantiated later on.
+    #[inline]
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        unsafe {
+            ffi::rive_rs_artboard_remap_colors(
+                self.inner.raw_artboard,
+                theme.from().as_ptr(),
+                theme.to().as_ptr(),
+                theme.len(),
+            );
+        }
+    }
+
+    /// Returns the content of all the visible, non-empty text objects of the artboard in draw
+    /// order, e.g. to expose it to assistive technologies.
+    ///
+    /// Bounds can be mapped to the viewport with the inverse of the transform used to draw the
+    /// artboard.
+    pub fn visible_text(&self) -> Vec<VisibleText> {
+        let mut texts = Vec::new();
+        unsafe {
+            ffi::rive_rs_artboard_visible_text(
+                self.inner.raw_artboard,
+                &mut texts as *mut Vec<(String, Rect)>,
+            );
+        }
+
+        texts
+            .into_iter()
+            .map(|(text, bounds)| VisibleText { text, bounds })
+            .collect()
+    }
+
+    /// Measures the text run named `name` and the text object containing it, e.g. to size native
+    /// UI around dynamic text. Returns `None` if there is no such run.
+    ///
+    /// The metrics reflect the layout of the last advance, so text changed with
+    /// [`TextValueRun::set_text`](components::TextValueRun::set_text) is only measured after the
+    /// next [`Artboard::advance`] or advance of a scene playing on the artboard. Run bounds
+    /// assume left-to-right text.
+    pub fn measure_text_run(&self, name: &str) -> Option<TextMetrics> {
+        let mut bounds = [0.0; 4];
+        let mut size = [0.0; 2];
+        let mut lines: Vec<[f32; 4]> = Vec::new();
+        let mut run_bounds = [0.0; 4];
+        let mut has_run_bounds = false;
+
+        let found = unsafe {
+            ffi::rive_rs_artboard_measure_text_run(
+                self.inner.raw_artboard,
+                name.as_ptr(),
+                name.len(),
+                &mut bounds as *mut [f32; 4],
+                &mut size as *mut [f32; 2],
+                &mut lines as *mut Vec<[f32; 4]>,
+                &mut run_bounds as *mut [f32; 4],
+                &mut has_run_bounds as *mut bool,
+            )
+        };
+
+        let rect = |[min_x, min_y, max_x, max_y]: [f32; 4]| Rect {
+            min: Point { x: min_x, y: min_y },
+            max: Point { x: max_x, y: max_y },
+        };
+        let [width, height] = size;
+
+        found.then(|| TextMetrics {
+            bounds: rect(bounds),
+            width,
+            height,
+            lines: lines
+                .into_iter()
+                .map(|[top, baseline, bottom, width]| LineMetrics {
+                    top,
+                    baseline,
+                    bottom,
+                    width,
+                })
+                .collect(),
+            run_bounds: has_run_bounds.then(|| rect(run_bounds)),
+        })
+    }
+
+    pub(crate) fn state_machine_count(&self) -> usize {
+        unsafe { ffi::rive_rs_artboard_state_machine_count(self.inner.raw_artboard) }
+    }
+
+    pub(crate) fn state_machine_name(&self, index: usize) -> &str {
+        let mut data = ptr::null();
+        let mut len = 0;
+
+        unsafe {
+            ffi::rive_rs_artboard_state_machine_name(
+                self.inner.raw_artboard,
+                index,
+                &mut data as *mut *const u8,
+                &mut len as *mut usize,
+            );
+            str::from_utf8(slice::from_raw_parts(data, len))
+                .expect("state machine name is invalid UTF-8")
+        }
+    }
+
+    pub(crate) fn linear_animation_count(&self) -> usize {
+        unsafe { ffi::rive_rs_artboard_linear_animation_count(self.inner.raw_artboard) }
+    }
+
+    pub(crate) fn linear_animation_name(&self, index: usize) -> &str {
+        let mut data = ptr::null();
+        let mut len = 0;
+
+        unsafe {
+            ffi::rive_rs_artboard_linear_animation_name(
+                self.inner.raw_artboard,
+                index,
+                &mut data as *mut *const u8,
+                &mut len as *mut usize,
+            );
+            str::from_utf8(slice::from_raw_parts(data, len))
+                .expect("linear animation name is invalid UTF-8")
+        }
+    }
+
+    /// Instantiates every scene of the artboard, state machines first, followed by linear
+    /// animations. Each scene is yielded together with its name. Scenes that fail to instantiate
+    /// are skipped.
+    ///
+    /// `S` is usually a boxed [`Scene`] trait object, e.g. `Box<dyn Scene<R>>`.
+    #[inline]
+    pub fn scenes<S>(&self) -> Scenes<R, S>
+    where
+        S: From<StateMachine<R>> + From<LinearAnimation<R>>,
+    {
+        let state_machine_count = self.state_machine_count();
+        let linear_animation_count = self.linear_animation_count();
+
+        Scenes {
+            artboard: Artboard::from_inner(self.inner.clone()),
+            state_machine_count,
+            index: 0,
+            end: state_machine_count + linear_animation_count,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Iterator over all the scenes of an [`Artboard`], created by [`Artboard::scenes`].
+pub struct Scenes<R: Renderer, S> {
+    artboard: Artboard<R>,
+    state_machine_count: usize,
+    index: usize,
+    end: usize,
+    _phantom: PhantomData<S>,
+}
+
+impl<R: Renderer, S> Iterator for Scenes<R, S>
+where
+    S: From<StateMachine<R>> + From<LinearAnimation<R>>,
+{
+    type Item = (String, S);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.end {
+            let index = self.index;
+            self.index += 1;
+
+            let scene = if index < self.state_machine_count {
+                let name = self.artboard.state_machine_name(index);
+
+                StateMachine::instantiate(&self.artboard, Handle::Index(index))
+                    .map(|state_machine| (name.to_owned(), state_machine.into()))
+            } else {
+                let index = index - self.state_machine_count;
+                let name = self.artboard.linear_animation_name(index);
+
+                LinearAnimation::instantiate(&self.artboard, Handle::Index(index))
+                    .map(|linear_animation| (name.to_owned(), linear_animation.into()))
+            };
+
+            if scene.is_some() {
+                return scene;
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.index))
+    }
+}
+
+impl<R: Renderer, S> FusedIterator for Scenes<R, S> where
+    S: From<StateMachine<R>> + From<LinearAnimation<R>>
+{
+}
+
+impl<R: Renderer, S> fmt::Debug for Scenes<R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scenes")
+            .field("index", &self.index)
+            .field("end", &self.end)
+            .finish()
+    }
 }
 
 impl<R: Renderer> Instantiate for Artboard<R> {
@@ -65,6 +454,8 @@ impl<R: Renderer> Instantiate for Artboard<R> {
 
     #[inline]
     fn instantiate(file: &Self::From, handle: Handle) -> Option<Self> {
+        span!("Artboard::instantiate");
+
         let mut raw_artboard: Option<NonNull<ffi::Artboard>> = None;
 
         match handle {
@@ -96,6 +487,31 @@ impl<R: Renderer> Instantiate for Artboard<R> {
             _phantom: PhantomData,
         })
     }
+
+    fn handles(file: &Self::From) -> Vec<(Handle, String)> {
+        let raw_file = file.as_inner().raw_file;
+        let count = unsafe { ffi::rive_rs_file_artboard_count(raw_file) };
+
+        (0..count)
+            .map(|index| {
+                let mut data = ptr::null();
+                let mut len = 0;
+
+                let name = unsafe {
+                    ffi::rive_rs_file_artboard_name(
+                        raw_file,
+                        index,
+                        &mut data as *mut *const u8,
+                        &mut len as *mut usize,
+                    );
+                    str::from_utf8(slice::from_raw_parts(data, len))
+                        .expect("artboard name is invalid UTF-8")
+                };
+
+                (Handle::Index(index), name.to_owned())
+            })
+            .collect()
+    }
 }
 
 impl<R: Renderer> fmt::Debug for Artboard<R> {
@@ -132,8 +548,12 @@ impl<R: Renderer> Scene<R> for Artboard<R> {
         str::from_utf8(bytes).expect("component name is invalid UTF-8")
     }
 
+    fn kind(&self) -> SceneKind {
+        SceneKind::Artboard
+    }
+
     fn r#loop(&self) -> Loop {
-        Loop::OneShot
+        unsafe { ffi::rive_rs_artboard_loop(self.inner.raw_artboard) }
     }
 
     fn is_translucent(&self) -> bool {
@@ -144,13 +564,15 @@ impl<R: Renderer> Scene<R> for Artboard<R> {
         None
     }
 
-    fn pointer_down(&mut self, _x: f32, _y: f32, _viewport: &Viewport) {}
+    fn pointer_down_artboard(&mut self, _x: f32, _y: f32) {}
 
-    fn pointer_move(&mut self, _x: f32, _y: f32, _viewport: &Viewport) {}
+    fn pointer_move_artboard(&mut self, _x: f32, _y: f32) {}
 
-    fn pointer_up(&mut self, _x: f32, _y: f32, _viewport: &Viewport) {}
+    fn pointer_up_artboard(&mut self, _x: f32, _y: f32) {}
 
     fn advance_and_apply(&mut self, _elapsed: Duration) -> bool {
+        span!("Artboard::advance_and_apply");
+
         unsafe {
             ffi::rive_rs_artboard_advance(self.inner.raw_artboard);
         }
@@ -159,6 +581,8 @@ impl<R: Renderer> Scene<R> for Artboard<R> {
     }
 
     fn draw(&self, renderer: &mut R) {
+        span!("Artboard::draw");
+
         unsafe {
             ffi::rive_rs_artboard_draw(
                 self.inner.raw_artboard,
@@ -169,42 +593,15 @@ impl<R: Renderer> Scene<R> for Artboard<R> {
         }
     }
 
-    fn advance_and_maybe_draw(
-        &mut self,
-        renderer: &mut R,
-        elapsed: Duration,
-        viewport: &mut Viewport,
-    ) -> bool {
-        let mut view_transform = [0.0; 6];
-        let mut inverse_view_transform = [0.0; 6];
-
-        unsafe {
-            crate::ffi::rive_rs_artboard_instance_transforms(
-                self.inner.raw_artboard,
-                viewport.width,
-                viewport.height,
-                view_transform.as_mut_ptr(),
-                inverse_view_transform.as_mut_ptr(),
-            );
-        }
-
-        viewport.inverse_view_transform = inverse_view_transform;
-
-        if !self.advance_and_apply(elapsed) {
-            // return false;
-        }
-
-        renderer.state_push();
-        renderer.transform(&view_transform);
-
-        self.draw(renderer);
-
-        renderer.state_pop();
-
-        true
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
     }
 
-    fn as_any(&self) -> &dyn core::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
+
+    fn artboard(&self) -> Artboard<R> {
+        Artboard::from_inner(self.inner.clone())
+    }
 }
@@ -0,0 +1,184 @@
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use core::fmt;
+
+use crate::{
+    ffi,
+    renderer::{Image, Renderer},
+};
+
+/// Content hash of an asset: its 64-bit FNV-1a hash together with its length.
+type Key = (u64, usize);
+
+fn key(data: &[u8]) -> Key {
+    let hash = data.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    (hash, data.len())
+}
+
+/// Cached assets whose contents have the same [`Key`]. Every asset is stored together with its
+/// contents, so that a hash collision never returns the wrong one.
+type Bucket<T> = Vec<(Box<[u8]>, T)>;
+
+fn find<'b, T>(buckets: &'b BTreeMap<Key, Bucket<T>>, data: &[u8]) -> Option<&'b T> {
+    buckets
+        .get(&key(data))?
+        .iter()
+        .find(|(contents, _)| **contents == *data)
+        .map(|(_, asset)| asset)
+}
+
+/// Cache of decoded images and fonts shared by the files loaded with
+/// [`File::with_asset_cache`](crate::File::with_asset_cache).
+///
+/// Assets are looked up by their encoded contents, so an image or font embedded in many files is
+/// only decoded and stored once. The contents are kept alongside the decoded assets in order to
+/// compare them on every hit. Cached assets stay alive until the cache is cleared or dropped,
+/// even if no file uses them anymore.
+pub struct AssetCache<R: Renderer> {
+    images: BTreeMap<Key, Bucket<Arc<R::Image>>>,
+    fonts: BTreeMap<Key, Bucket<*const ffi::Font>>,
+}
+
+impl<R: Renderer> AssetCache<R> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached images and fonts.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.images.values().map(Vec::len).sum::<usize>()
+            + self.fonts.values().map(Vec::len).sum::<usize>()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all assets from the cache. Files already loaded keep the assets they use.
+    pub fn clear(&mut self) {
+        self.images.clear();
+
+        for (_, bucket) in core::mem::take(&mut self.fonts) {
+            for (_, font) in bucket {
+                unsafe {
+                    ffi::rive_rs_font_release(font);
+                }
+            }
+        }
+    }
+}
+
+impl<R: Renderer> ffi::AssetCache<R> for AssetCache<R> {
+    fn image(&mut self, data: &[u8]) -> Option<Arc<R::Image>> {
+        if let Some(image) = find(&self.images, data) {
+            return Some(image.clone());
+        }
+
+        let image = Arc::new(R::Image::decode(data)?);
+        self.images
+            .entry(key(data))
+            .or_default()
+            .push((data.into(), image.clone()));
+
+        Some(image)
+    }
+
+    fn font(&self, data: &[u8]) -> *const ffi::Font {
+        find(&self.fonts, data)
+            .copied()
+            .unwrap_or(core::ptr::null())
+    }
+
+    fn insert_font(&mut self, data: &[u8], font: *const ffi::Font) {
+        let bucket = self.fonts.entry(key(data)).or_default();
+
+        match bucket.iter_mut().find(|(contents, _)| **contents == *data) {
+            Some((_, cached)) => unsafe {
+                ffi::rive_rs_font_release(core::mem::replace(cached, font));
+            },
+            None => bucket.push((data.into(), font)),
+        }
+    }
+}
+
+/// An [`AssetCache`] shared by files loading on several threads at once. The lock is only held
+/// to look assets up and insert them, so images are decoded in parallel. If two threads decode
+/// the same image, the first one to insert it wins.
+#[cfg(feature = "std")]
+pub(crate) struct SharedAssetCache<'c, R: Renderer>(
+    pub &'c std::sync::Mutex<&'c mut AssetCache<R>>,
+);
+
+#[cfg(feature = "std")]
+impl<'c, R: Renderer> SharedAssetCache<'c, R> {
+    fn lock(&self) -> std::sync::MutexGuard<'c, &'c mut AssetCache<R>> {
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Renderer> ffi::AssetCache<R> for SharedAssetCache<'_, R> {
+    fn image(&mut self, data: &[u8]) -> Option<Arc<R::Image>> {
+        if let Some(image) = find(&self.lock().images, data) {
+            return Some(image.clone());
+        }
+
+        let image = Arc::new(R::Image::decode(data)?);
+
+        let mut asset_cache = self.lock();
+        if let Some(cached) = find(&asset_cache.images, data) {
+            return Some(cached.clone());
+        }
+
+        asset_cache
+            .images
+            .entry(key(data))
+            .or_default()
+            .push((data.into(), image.clone()));
+
+        Some(image)
+    }
+
+    fn font(&self, data: &[u8]) -> *const ffi::Font {
+        self.lock().font(data)
+    }
+
+    fn insert_font(&mut self, data: &[u8], font: *const ffi::Font) {
+        self.lock().insert_font(data, font);
+    }
+}
+
+impl<R: Renderer> Default for AssetCache<R> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            images: BTreeMap::new(),
+            fonts: BTreeMap::new(),
+        }
+    }
+}
+
+impl<R: Renderer> Drop for AssetCache<R> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<R: Renderer> fmt::Debug for AssetCache<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssetCache")
+            .field("images", &self.images.len())
+            .field("fonts", &self.fonts.len())
+            .finish()
+    }
+}
+
+unsafe impl<R: Renderer> Send for AssetCache<R> where R::Image: Send + Sync {}
+unsafe impl<R: Renderer> Sync for AssetCache<R> where R::Image: Send + Sync {}
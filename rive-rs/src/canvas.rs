@@ -0,0 +1,626 @@
+//! Fallback back-end drawing through the browser's Canvas 2D API.
+//!
+//! Vello needs WebGPU, which not every browser has enabled yet. This back-end only needs a
+//! `CanvasRenderingContext2d`, so `wasm32` builds can fall back to it when creating a WebGPU
+//! adapter fails. It trades quality and speed for reach: layers are drawn straight through
+//! without their opacity and tint, and image meshes show faint seams between triangles.
+//!
+//! ```no_run
+//! # use rive_rs::{canvas::Renderer, scene::Scene, Viewport};
+//! # fn frame(
+//! #     scene: &mut dyn Scene<Renderer>,
+//! #     context: web_sys::CanvasRenderingContext2d,
+//! #     viewport: &mut Viewport,
+//! # ) {
+//! let mut renderer = Renderer::new(context);
+//!
+//! renderer.clear();
+//! scene.draw_in(&mut renderer, viewport);
+//! # }
+//! ```
+
+use std::{cell::OnceCell, fmt, io::Cursor};
+
+use image::io::Reader;
+use wasm_bindgen::{Clamped, JsCast, JsValue};
+use web_sys::{
+    CanvasGradient, CanvasRenderingContext2d, CanvasWindingRule, HtmlCanvasElement, ImageData,
+    Path2d,
+};
+
+use crate::{
+    path::{Commands, FillRule, Verb},
+    renderer::{self, BlendMode, PaintStyle, StrokeCap, StrokeJoin},
+};
+
+fn to_css_color(color: renderer::Color) -> String {
+    format!(
+        "rgba({}, {}, {}, {})",
+        color.r,
+        color.g,
+        color.b,
+        color.a as f32 / 255.0
+    )
+}
+
+fn to_composite_operation(blend_mode: BlendMode) -> &'static str {
+    match blend_mode {
+        BlendMode::SrcOver => "source-over",
+        BlendMode::Screen => "screen",
+        BlendMode::Overlay => "overlay",
+        BlendMode::Darken => "darken",
+        BlendMode::Lighten => "lighten",
+        BlendMode::ColorDodge => "color-dodge",
+        BlendMode::ColorBurn => "color-burn",
+        BlendMode::HardLight => "hard-light",
+        BlendMode::SoftLight => "soft-light",
+        BlendMode::Difference => "difference",
+        BlendMode::Exclusion => "exclusion",
+        BlendMode::Multiply => "multiply",
+        BlendMode::Hue => "hue",
+        BlendMode::Saturation => "saturation",
+        BlendMode::Color => "color",
+        BlendMode::Luminosity => "luminosity",
+    }
+}
+
+fn to_winding_rule(fill_rule: FillRule) -> CanvasWindingRule {
+    match fill_rule {
+        FillRule::NonZero => CanvasWindingRule::Nonzero,
+        FillRule::EvenOdd => CanvasWindingRule::Evenodd,
+    }
+}
+
+fn transform_point(t: &[f32; 6], x: f32, y: f32) -> (f32, f32) {
+    (t[0] * x + t[2] * y + t[4], t[1] * x + t[3] * y + t[5])
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PathCommand {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    CubicTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+/// Path recorded on the Rust side and converted to a `Path2D` the first time it's drawn after a
+/// change.
+pub struct Path {
+    commands: Vec<PathCommand>,
+    fill_rule: FillRule,
+    path_2d: OnceCell<Path2d>,
+}
+
+impl Path {
+    fn push(&mut self, command: PathCommand) {
+        self.commands.push(command);
+        self.path_2d.take();
+    }
+
+    fn path_2d(&self) -> &Path2d {
+        self.path_2d.get_or_init(|| {
+            let path_2d = Path2d::new().expect("Path2D is not supported");
+
+            for command in &self.commands {
+                match *command {
+                    PathCommand::MoveTo(x, y) => path_2d.move_to(x as f64, y as f64),
+                    PathCommand::LineTo(x, y) => path_2d.line_to(x as f64, y as f64),
+                    PathCommand::CubicTo(ox, oy, ix, iy, x, y) => path_2d.bezier_curve_to(
+                        ox as f64, oy as f64, ix as f64, iy as f64, x as f64, y as f64,
+                    ),
+                    PathCommand::Close => path_2d.close_path(),
+                }
+            }
+
+            path_2d
+        })
+    }
+}
+
+impl Default for Path {
+    fn default() -> Self {
+        Self {
+            commands: Vec::new(),
+            fill_rule: FillRule::NonZero,
+            path_2d: OnceCell::new(),
+        }
+    }
+}
+
+impl renderer::Path for Path {
+    fn new(commands: &mut Commands, fill_rule: FillRule) -> Self {
+        let commands = commands
+            .map(|(verb, points)| match verb {
+                Verb::Move => PathCommand::MoveTo(points[0].x, points[0].y),
+                Verb::Line => PathCommand::LineTo(points[0].x, points[0].y),
+                Verb::Cubic => PathCommand::CubicTo(
+                    points[0].x,
+                    points[0].y,
+                    points[1].x,
+                    points[1].y,
+                    points[2].x,
+                    points[2].y,
+                ),
+                Verb::Close => PathCommand::Close,
+            })
+            .collect();
+
+        Self {
+            commands,
+            fill_rule,
+            path_2d: OnceCell::new(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.commands.clear();
+        self.path_2d.take();
+    }
+
+    fn extend(&mut self, from: &Self, transform: &[f32; 6]) {
+        self.commands
+            .extend(from.commands.iter().map(|command| match *command {
+                PathCommand::MoveTo(x, y) => {
+                    let (x, y) = transform_point(transform, x, y);
+                    PathCommand::MoveTo(x, y)
+                }
+                PathCommand::LineTo(x, y) => {
+                    let (x, y) = transform_point(transform, x, y);
+                    PathCommand::LineTo(x, y)
+                }
+                PathCommand::CubicTo(ox, oy, ix, iy, x, y) => {
+                    let (ox, oy) = transform_point(transform, ox, oy);
+                    let (ix, iy) = transform_point(transform, ix, iy);
+                    let (x, y) = transform_point(transform, x, y);
+                    PathCommand::CubicTo(ox, oy, ix, iy, x, y)
+                }
+                PathCommand::Close => PathCommand::Close,
+            }));
+        self.path_2d.take();
+    }
+
+    fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.fill_rule = fill_rule;
+    }
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.push(PathCommand::MoveTo(x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push(PathCommand::LineTo(x, y));
+    }
+
+    fn cubic_to(&mut self, ox: f32, oy: f32, ix: f32, iy: f32, x: f32, y: f32) {
+        self.push(PathCommand::CubicTo(ox, oy, ix, iy, x, y));
+    }
+
+    fn close(&mut self) {
+        self.push(PathCommand::Close);
+    }
+}
+
+impl fmt::Debug for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Path")
+            .field("commands", &self.commands)
+            .field("fill_rule", &self.fill_rule)
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug)]
+enum GradientKind {
+    Linear { sx: f32, sy: f32, ex: f32, ey: f32 },
+    Radial { cx: f32, cy: f32, radius: f32 },
+}
+
+/// Gradient description, turned into a `CanvasGradient` of the context it's drawn with.
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    kind: GradientKind,
+    stops: Vec<(f32, String)>,
+}
+
+impl Gradient {
+    fn to_canvas_gradient(&self, context: &CanvasRenderingContext2d) -> Option<CanvasGradient> {
+        let gradient = match self.kind {
+            GradientKind::Linear { sx, sy, ex, ey } => {
+                context.create_linear_gradient(sx as f64, sy as f64, ex as f64, ey as f64)
+            }
+            GradientKind::Radial { cx, cy, radius } => context
+                .create_radial_gradient(
+                    cx as f64,
+                    cy as f64,
+                    0.0,
+                    cx as f64,
+                    cy as f64,
+                    radius as f64,
+                )
+                .ok()?,
+        };
+
+        for (offset, color) in &self.stops {
+            gradient
+                .add_color_stop(offset.clamp(0.0, 1.0), color)
+                .ok()?;
+        }
+
+        Some(gradient)
+    }
+}
+
+fn stops(colors: &[renderer::Color], stops: &[f32]) -> Vec<(f32, String)> {
+    stops
+        .iter()
+        .zip(colors)
+        .map(|(&offset, &color)| (offset, to_css_color(color)))
+        .collect()
+}
+
+impl renderer::Gradient for Gradient {
+    fn new_linear(
+        sx: f32,
+        sy: f32,
+        ex: f32,
+        ey: f32,
+        colors: &[renderer::Color],
+        stops: &[f32],
+    ) -> Self {
+        Self {
+            kind: GradientKind::Linear { sx, sy, ex, ey },
+            stops: self::stops(colors, stops),
+        }
+    }
+
+    fn new_radial(
+        cx: f32,
+        cy: f32,
+        radius: f32,
+        colors: &[renderer::Color],
+        stops: &[f32],
+    ) -> Self {
+        Self {
+            kind: GradientKind::Radial { cx, cy, radius },
+            stops: self::stops(colors, stops),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Brush {
+    Color(JsValue),
+    Gradient(Gradient),
+}
+
+#[derive(Clone, Debug)]
+pub struct Paint {
+    style: PaintStyle,
+    brush: Brush,
+    thickness: f32,
+    join: StrokeJoin,
+    cap: StrokeCap,
+    blend_mode: BlendMode,
+}
+
+impl Default for Paint {
+    fn default() -> Self {
+        Self {
+            style: PaintStyle::Fill,
+            brush: Brush::Color(JsValue::from_str("black")),
+            thickness: 1.0,
+            join: StrokeJoin::Miter,
+            cap: StrokeCap::Butt,
+            blend_mode: BlendMode::SrcOver,
+        }
+    }
+}
+
+impl renderer::Paint for Paint {
+    type Gradient = Gradient;
+
+    fn set_style(&mut self, style: PaintStyle) {
+        self.style = style;
+    }
+
+    fn set_color(&mut self, color: renderer::Color) {
+        self.brush = Brush::Color(JsValue::from_str(&to_css_color(color)));
+    }
+
+    fn set_thickness(&mut self, thickness: f32) {
+        self.thickness = thickness;
+    }
+
+    fn set_join(&mut self, join: StrokeJoin) {
+        self.join = join;
+    }
+
+    fn set_cap(&mut self, cap: StrokeCap) {
+        self.cap = cap;
+    }
+
+    fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    fn set_gradient(&mut self, gradient: &Self::Gradient) {
+        self.brush = Brush::Gradient(gradient.clone());
+    }
+}
+
+/// Image decoded into an off-screen canvas, so that it can be drawn with `drawImage`.
+#[derive(Clone, Debug)]
+pub struct Image {
+    canvas: HtmlCanvasElement,
+    width: u32,
+    height: u32,
+}
+
+impl renderer::Image for Image {
+    fn decode(data: &[u8]) -> Option<Self> {
+        let image = Reader::new(Cursor::new(data))
+            .with_guessed_format()
+            .ok()?
+            .decode()
+            .ok()?
+            .into_rgba8();
+        let width = image.width();
+        let height = image.height();
+
+        let canvas: HtmlCanvasElement = web_sys::window()?
+            .document()?
+            .create_element("canvas")
+            .ok()?
+            .dyn_into()
+            .ok()?;
+        canvas.set_width(width);
+        canvas.set_height(height);
+
+        let context: CanvasRenderingContext2d = canvas.get_context("2d").ok()??.dyn_into().ok()?;
+        let image_data = ImageData::new_with_u8_clamped_array_and_sh(
+            Clamped(image.as_raw().as_slice()),
+            width,
+            height,
+        )
+        .ok()?;
+        context.put_image_data(&image_data, 0.0, 0.0).ok()?;
+
+        Some(Self {
+            canvas,
+            width,
+            height,
+        })
+    }
+}
+
+fn read_f32s(buffer: &[u8]) -> impl Iterator<Item = f32> + '_ {
+    buffer
+        .chunks_exact(4)
+        .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u16s(buffer: &[u8]) -> impl Iterator<Item = u16> + '_ {
+    buffer
+        .chunks_exact(2)
+        .map(|bytes| u16::from_ne_bytes([bytes[0], bytes[1]]))
+}
+
+/// Returns the affine transform mapping the triangle `from` onto the triangle `to`, or `None` if
+/// `from` is degenerate.
+fn triangle_transform(from: [(f32, f32); 3], to: [(f32, f32); 3]) -> Option<[f64; 6]> {
+    let [s0, s1, s2] = from.map(|(x, y)| (x as f64, y as f64));
+    let [d0, d1, d2] = to.map(|(x, y)| (x as f64, y as f64));
+
+    let (a, b) = (s1.0 - s0.0, s1.1 - s0.1);
+    let (c, d) = (s2.0 - s0.0, s2.1 - s0.1);
+    let det = a * d - b * c;
+
+    if det == 0.0 {
+        return None;
+    }
+
+    let (dx1, dy1) = (d1.0 - d0.0, d1.1 - d0.1);
+    let (dx2, dy2) = (d2.0 - d0.0, d2.1 - d0.1);
+
+    let m11 = (dx1 * d - dx2 * b) / det;
+    let m12 = (dx2 * a - dx1 * c) / det;
+    let m21 = (dy1 * d - dy2 * b) / det;
+    let m22 = (dy2 * a - dy1 * c) / det;
+
+    Some([
+        m11,
+        m21,
+        m12,
+        m22,
+        d0.0 - (m11 * s0.0 + m12 * s0.1),
+        d0.1 - (m21 * s0.0 + m22 * s0.1),
+    ])
+}
+
+/// Renderer drawing into a `CanvasRenderingContext2d`.
+///
+/// Unlike the Vello renderer, it draws immediately, so the context's transform and clip are
+/// shared with anything else drawing into it between [`Renderer::state_push`] and
+/// [`Renderer::state_pop`].
+///
+/// [`Renderer::state_push`]: renderer::Renderer::state_push
+/// [`Renderer::state_pop`]: renderer::Renderer::state_pop
+#[derive(Clone, Debug)]
+pub struct Renderer {
+    context: CanvasRenderingContext2d,
+}
+
+impl Renderer {
+    #[inline]
+    pub fn new(context: CanvasRenderingContext2d) -> Self {
+        Self { context }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &CanvasRenderingContext2d {
+        &self.context
+    }
+
+    /// Resets the context's transform and clears its whole canvas.
+    pub fn clear(&mut self) {
+        // Canvas calls only fail for invalid arguments, which a Rive file can't produce.
+        let _ = self.context.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+        if let Some(canvas) = self.context.canvas() {
+            self.context
+                .clear_rect(0.0, 0.0, canvas.width() as f64, canvas.height() as f64);
+        }
+    }
+
+    fn set_blend_mode(&self, blend_mode: BlendMode) {
+        let _ = self
+            .context
+            .set_global_composite_operation(to_composite_operation(blend_mode));
+    }
+}
+
+impl renderer::Renderer for Renderer {
+    type Buffer = Vec<u8>;
+    type Path = Path;
+    type Paint = Paint;
+    type Gradient = Gradient;
+    type Image = Image;
+
+    #[inline]
+    fn state_push(&mut self) {
+        self.context.save();
+    }
+
+    #[inline]
+    fn state_pop(&mut self) {
+        self.context.restore();
+    }
+
+    #[inline]
+    fn transform(&mut self, t: &[f32; 6]) {
+        let _ = self.context.transform(
+            t[0] as f64,
+            t[1] as f64,
+            t[2] as f64,
+            t[3] as f64,
+            t[4] as f64,
+            t[5] as f64,
+        );
+    }
+
+    #[inline]
+    fn set_clip(&mut self, path: &Self::Path) {
+        self.context
+            .clip_with_path_2d_and_winding(path.path_2d(), to_winding_rule(path.fill_rule));
+    }
+
+    fn draw_path(&mut self, path: &Self::Path, paint: &Self::Paint) {
+        let style = match &paint.brush {
+            Brush::Color(color) => color.clone(),
+            Brush::Gradient(gradient) => match gradient.to_canvas_gradient(&self.context) {
+                Some(gradient) => gradient.into(),
+                None => return,
+            },
+        };
+
+        self.set_blend_mode(paint.blend_mode);
+
+        match paint.style {
+            PaintStyle::Fill => {
+                self.context.set_fill_style(&style);
+                self.context
+                    .fill_with_path_2d_and_winding(path.path_2d(), to_winding_rule(path.fill_rule));
+            }
+            PaintStyle::Stroke => {
+                self.context.set_stroke_style(&style);
+                self.context.set_line_width(paint.thickness as f64);
+                self.context.set_line_join(match paint.join {
+                    StrokeJoin::Miter => "miter",
+                    StrokeJoin::Round => "round",
+                    StrokeJoin::Bevel => "bevel",
+                });
+                self.context.set_line_cap(match paint.cap {
+                    StrokeCap::Butt => "butt",
+                    StrokeCap::Round => "round",
+                    StrokeCap::Square => "square",
+                });
+                self.context.stroke_with_path(path.path_2d());
+            }
+        }
+    }
+
+    fn draw_image(&mut self, image: &Self::Image, blend_mode: BlendMode, opacity: f32) {
+        self.context.save();
+        self.set_blend_mode(blend_mode);
+        self.context.set_global_alpha(opacity as f64);
+
+        let _ = self.context.draw_image_with_html_canvas_element(
+            &image.canvas,
+            image.width as f64 * -0.5,
+            image.height as f64 * -0.5,
+        );
+
+        self.context.restore();
+    }
+
+    fn draw_image_mesh(
+        &mut self,
+        image: &Self::Image,
+        vertices: &Self::Buffer,
+        uvs: &Self::Buffer,
+        indices: &Self::Buffer,
+        blend_mode: BlendMode,
+        opacity: f32,
+    ) {
+        let vertices: Vec<f32> = read_f32s(vertices).collect();
+        let uvs: Vec<f32> = read_f32s(uvs).collect();
+        let indices: Vec<u16> = read_u16s(indices).collect();
+
+        let vertex = |index: u16| {
+            let index = index as usize * 2;
+            (vertices[index], vertices[index + 1])
+        };
+        let texel = |index: u16| {
+            let index = index as usize * 2;
+            (
+                uvs[index] * image.width as f32,
+                uvs[index + 1] * image.height as f32,
+            )
+        };
+
+        self.context.save();
+        self.set_blend_mode(blend_mode);
+        self.context.set_global_alpha(opacity as f64);
+
+        for triangle in indices.chunks_exact(3) {
+            let points = [
+                vertex(triangle[0]),
+                vertex(triangle[1]),
+                vertex(triangle[2]),
+            ];
+            let texels = [texel(triangle[0]), texel(triangle[1]), texel(triangle[2])];
+
+            let Some(t) = triangle_transform(texels, points) else {
+                continue;
+            };
+            let Ok(clip) = Path2d::new() else {
+                continue;
+            };
+
+            clip.move_to(points[0].0 as f64, points[0].1 as f64);
+            clip.line_to(points[1].0 as f64, points[1].1 as f64);
+            clip.line_to(points[2].0 as f64, points[2].1 as f64);
+            clip.close_path();
+
+            self.context.save();
+            self.context.clip_with_path_2d(&clip);
+            let _ = self.context.transform(t[0], t[1], t[2], t[3], t[4], t[5]);
+            let _ = self
+                .context
+                .draw_image_with_html_canvas_element(&image.canvas, 0.0, 0.0);
+            self.context.restore();
+        }
+
+        self.context.restore();
+    }
+}
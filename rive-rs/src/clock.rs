@@ -0,0 +1,135 @@
+//! Sources of time for advancing scenes.
+//!
+//! Playback code that takes a [`Clock`] instead of reading the system time directly works the
+//! same with wall-clock time, a browser's `performance.now()`, a game engine's fixed step, or a
+//! mock clock in tests.
+
+use core::time::Duration;
+
+/// A source of the time passed between frames.
+pub trait Clock {
+    /// Returns the time elapsed since the previous call, or since the clock was created or reset
+    /// for the first call.
+    fn tick(&mut self) -> Duration;
+}
+
+/// Closures returning the elapsed time can be used as clocks, e.g. to wrap a host timer.
+impl<F: FnMut() -> Duration> Clock for F {
+    #[inline]
+    fn tick(&mut self) -> Duration {
+        self()
+    }
+}
+
+/// Clock measuring wall-clock time with [`std::time::Instant`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    last: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl SystemClock {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            last: std::time::Instant::now(),
+        }
+    }
+
+    /// Restarts measuring from now, e.g. after playback was paused.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.last = std::time::Instant::now();
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for SystemClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    #[inline]
+    fn tick(&mut self) -> Duration {
+        let now = std::time::Instant::now();
+        let elapsed = now - self.last;
+        self.last = now;
+
+        elapsed
+    }
+}
+
+/// Clock advancing by the same step on every tick, e.g. to export frames at a fixed frame rate
+/// or to follow a game engine's fixed update.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FixedClock {
+    step: Duration,
+}
+
+impl FixedClock {
+    #[inline]
+    pub fn new(step: Duration) -> Self {
+        Self { step }
+    }
+
+    /// Creates a clock ticking `fps` times per second.
+    #[inline]
+    pub fn from_fps(fps: u32) -> Self {
+        Self::new(Duration::from_secs(1) / fps.max(1))
+    }
+
+    #[inline]
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+}
+
+impl Clock for FixedClock {
+    #[inline]
+    fn tick(&mut self) -> Duration {
+        self.step
+    }
+}
+
+/// Clock that only moves when told to, for deterministic tests.
+///
+/// ```
+/// # use core::time::Duration;
+/// # use rive_rs::clock::{Clock, ManualClock};
+/// let mut clock = ManualClock::default();
+///
+/// clock.advance(Duration::from_millis(10));
+/// clock.advance(Duration::from_millis(6));
+///
+/// assert_eq!(clock.tick(), Duration::from_millis(16));
+/// assert_eq!(clock.tick(), Duration::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ManualClock {
+    pending: Duration,
+}
+
+impl ManualClock {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward by `elapsed`, which is returned by the next tick.
+    #[inline]
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.pending += elapsed;
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn tick(&mut self) -> Duration {
+        core::mem::take(&mut self.pending)
+    }
+}
@@ -0,0 +1,179 @@
+//! Debug overlays drawn on top of a scene to diagnose hit-testing and layout issues.
+
+use alloc::vec::Vec;
+
+use bitflags::bitflags;
+
+use crate::{
+    ffi,
+    path::Point,
+    renderer::{Color, Paint, PaintStyle, Path, Renderer},
+    scene::{Scene, Viewport},
+};
+
+bitflags! {
+    /// Layers drawn by [`draw_overlay`].
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct Layers: u32 {
+        /// The bounds of the artboard.
+        const ARTBOARD_BOUNDS = 1 << 0;
+        /// The world bounds of every shape.
+        const COMPONENT_BOUNDS = 1 << 1;
+        /// The world bounds of the shapes hit-tested by the enabled listeners of a state machine.
+        const LISTENER_HIT_AREAS = 1 << 2;
+        /// Every bone, as a line from its origin to its tip.
+        const BONES = 1 << 3;
+    }
+}
+
+const ARTBOARD_BOUNDS_COLOR: Color = Color {
+    r: 0xff,
+    g: 0xff,
+    b: 0xff,
+    a: 0xc0,
+};
+const COMPONENT_BOUNDS_COLOR: Color = Color {
+    r: 0x00,
+    g: 0xc0,
+    b: 0xff,
+    a: 0xc0,
+};
+const LISTENER_HIT_AREA_COLOR: Color = Color {
+    r: 0xff,
+    g: 0x00,
+    b: 0xc0,
+    a: 0x60,
+};
+const BONE_COLOR: Color = Color {
+    r: 0xff,
+    g: 0xd0,
+    b: 0x00,
+    a: 0xff,
+};
+
+/// Thickness of the overlay's lines in pixels, independent of the view's scale.
+const THICKNESS: f32 = 1.0;
+
+fn rect<P: Path>(path: &mut P, min: Point, max: Point) {
+    path.move_to(min.x, min.y);
+    path.line_to(max.x, min.y);
+    path.line_to(max.x, max.y);
+    path.line_to(min.x, max.y);
+    path.close();
+}
+
+fn paint<P: Paint>(style: PaintStyle, color: Color, thickness: f32) -> P {
+    let mut paint = P::default();
+
+    paint.set_style(style);
+    paint.set_color(color);
+    paint.set_thickness(thickness);
+
+    paint
+}
+
+/// Draws the debug `layers` of `scene` on top of it.
+///
+/// The overlay is drawn with the same view transform [`Scene::advance_and_maybe_draw`] uses for
+/// `viewport`, so it should be called right after it with the same renderer and viewport.
+/// [`Layers::LISTENER_HIT_AREAS`] are only drawn for state machines.
+pub fn draw_overlay<R, S>(scene: &S, renderer: &mut R, viewport: &Viewport, layers: Layers)
+where
+    R: Renderer,
+    S: Scene<R> + ?Sized,
+{
+    if layers.is_empty() {
+        return;
+    }
+
+    let artboard = scene.artboard();
+    let raw_artboard = unsafe { artboard.as_raw() };
+
+    let (view_transform, _) = viewport.view_transforms(raw_artboard);
+
+    // The view transform only scales uniformly and translates.
+    let scale = view_transform[0].abs();
+    let thickness = if scale > 0.0 {
+        THICKNESS / scale
+    } else {
+        THICKNESS
+    };
+
+    let mut bounds = [0.0; 4];
+    unsafe {
+        ffi::rive_rs_artboard_bounds(raw_artboard, bounds.as_mut_ptr());
+    }
+    let [min_x, min_y, max_x, max_y] = bounds;
+
+    renderer.state_push();
+    renderer.transform(&view_transform);
+    // World coordinates are relative to the artboard's origin, and its frame starts at the
+    // top-left corner of its bounds.
+    renderer.transform(&[1.0, 0.0, 0.0, 1.0, -min_x, -min_y]);
+
+    if layers.contains(Layers::ARTBOARD_BOUNDS) {
+        let mut path = R::Path::default();
+        rect(
+            &mut path,
+            Point { x: min_x, y: min_y },
+            Point { x: max_x, y: max_y },
+        );
+
+        renderer.draw_path(
+            &path,
+            &paint(PaintStyle::Stroke, ARTBOARD_BOUNDS_COLOR, thickness),
+        );
+    }
+
+    if layers.contains(Layers::COMPONENT_BOUNDS) {
+        let mut path = R::Path::default();
+        let mut points: Vec<Point> = Vec::new();
+        unsafe {
+            ffi::rive_rs_artboard_shape_bounds(raw_artboard, &mut points as *mut Vec<Point>);
+        }
+
+        for bounds in points.chunks_exact(2) {
+            rect(&mut path, bounds[0], bounds[1]);
+        }
+
+        renderer.draw_path(
+            &path,
+            &paint(PaintStyle::Stroke, COMPONENT_BOUNDS_COLOR, thickness),
+        );
+    }
+
+    if layers.contains(Layers::LISTENER_HIT_AREAS) {
+        if let Some(state_machine) = scene.as_state_machine() {
+            let mut path = R::Path::default();
+
+            for bounds in state_machine.listener_bounds().chunks_exact(2) {
+                rect(&mut path, bounds[0], bounds[1]);
+            }
+
+            renderer.draw_path(
+                &path,
+                &paint(PaintStyle::Fill, LISTENER_HIT_AREA_COLOR, thickness),
+            );
+        }
+    }
+
+    if layers.contains(Layers::BONES) {
+        let mut path = R::Path::default();
+        let mut points: Vec<Point> = Vec::new();
+        unsafe {
+            ffi::rive_rs_artboard_bones(raw_artboard, &mut points as *mut Vec<Point>);
+        }
+
+        for bone in points.chunks_exact(2) {
+            path.move_to(bone[0].x, bone[0].y);
+            path.line_to(bone[1].x, bone[1].y);
+        }
+
+        renderer.draw_path(
+            &path,
+            &paint(PaintStyle::Stroke, BONE_COLOR, thickness * 2.0),
+        );
+    }
+
+    renderer.state_pop();
+}
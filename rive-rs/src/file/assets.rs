@@ -0,0 +1,98 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::ffi;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AssetKind {
+    Image,
+    Font,
+}
+
+/// Why an asset failed to resolve.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UnresolvedReason {
+    /// The contents of the asset are not embedded in the file but referenced, e.g. hosted on
+    /// Rive's CDN, and need to be provided separately.
+    NotEmbedded,
+    /// The contents of the asset are embedded in the file but could not be decoded by the
+    /// renderer.
+    DecodeFailed,
+    /// The asset is a font and the crate was built without the `text` feature.
+    Unsupported,
+}
+
+impl fmt::Display for UnresolvedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnresolvedReason::NotEmbedded => f.write_str("contents are not embedded in the file"),
+            UnresolvedReason::DecodeFailed => f.write_str("contents could not be decoded"),
+            UnresolvedReason::Unsupported => f.write_str("text is not supported by this build"),
+        }
+    }
+}
+
+/// What is drawn in place of an unresolved asset.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Placeholder {
+    /// Images using the asset are not drawn.
+    Hidden,
+    /// Text styled with the font is not drawn, since no fallback fonts are configured.
+    NoText,
+}
+
+/// An image or font of a file that failed to resolve, as returned by
+/// [`File::unresolved_assets`](crate::File::unresolved_assets).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UnresolvedAsset {
+    /// Name of the asset in the Rive editor.
+    pub name: String,
+    pub kind: AssetKind,
+    pub reason: UnresolvedReason,
+    pub placeholder: Placeholder,
+}
+
+/// Lists the assets of the imported `raw_file` that failed to resolve. `embedded` tells missing
+/// contents apart from undecodable ones, with an entry per image and font asset of the file.
+pub(crate) fn unresolved(raw_file: *const ffi::File, embedded: &[bool]) -> Vec<UnresolvedAsset> {
+    let mut assets = Vec::new();
+
+    unsafe {
+        ffi::rive_rs_file_assets(raw_file, &mut assets as *mut _);
+    }
+
+    // Only trust the scan if it found the same assets as the runtime.
+    let embedded = Some(embedded).filter(|embedded| embedded.len() == assets.len());
+
+    assets
+        .into_iter()
+        .enumerate()
+        .filter(|(_, (_, _, is_loaded))| !is_loaded)
+        .map(|(i, (tag, name, _))| {
+            let is_embedded = match &embedded {
+                Some(embedded) => embedded[i],
+                None => true,
+            };
+
+            let (kind, placeholder) = match tag {
+                ffi::AssetTag::Image => (AssetKind::Image, Placeholder::Hidden),
+                ffi::AssetTag::Font => (AssetKind::Font, Placeholder::NoText),
+            };
+
+            let reason = if kind == AssetKind::Font && !cfg!(feature = "text") {
+                UnresolvedReason::Unsupported
+            } else if !is_embedded {
+                UnresolvedReason::NotEmbedded
+            } else {
+                UnresolvedReason::DecodeFailed
+            };
+
+            UnresolvedAsset {
+                name,
+                kind,
+                reason,
+                placeholder,
+            }
+        })
+        .collect()
+}
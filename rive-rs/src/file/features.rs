@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+
+use bitflags::bitflags;
+
+use super::{reader, Error};
+
+// Type keys of the objects that mark the use of a feature, as generated in rive-cpp's
+// `*_base.hpp` headers. Some of them are newer than the embedded runtime, which skips them on
+// import, so they can only be detected before importing.
+const IMAGE_ASSET: u32 = 105;
+const FILE_ASSET_CONTENTS: u32 = 106;
+const NESTED_ARTBOARD: u32 = 92;
+const TEXT: u32 = 134;
+const FONT_ASSET: u32 = 141;
+const AUDIO_ASSET: u32 = 406;
+const AUDIO_EVENT: u32 = 407;
+const VIEW_MODEL: u32 = 435;
+const DATA_BIND: u32 = 446;
+const FEATHER: u32 = 533;
+
+bitflags! {
+    /// Features used by a Rive file, as returned by [`File::features`](crate::File::features).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct Features: u32 {
+        /// The file contains text objects, which need the `text` feature to render.
+        const TEXT = 1 << 0;
+        /// The file contains audio events or audio assets.
+        const AUDIO = 1 << 1;
+        /// The file contains view models or data bindings.
+        const DATA_BINDING = 1 << 2;
+        /// The file contains feathered fills or strokes.
+        const FEATHERING = 1 << 3;
+        /// The file contains artboards nested in other artboards.
+        const NESTED_ARTBOARDS = 1 << 4;
+        /// The file contains assets whose contents are not embedded in the file and need to be
+        /// provided separately.
+        const REFERENCED_ASSETS = 1 << 5;
+    }
+}
+
+impl Features {
+    /// Returns the features that files can use but that this build of the runtime can't render.
+    pub(crate) fn unsupported() -> Self {
+        let unsupported = Self::AUDIO | Self::DATA_BINDING | Self::FEATHERING;
+
+        if cfg!(feature = "text") {
+            unsupported
+        } else {
+            unsupported | Self::TEXT
+        }
+    }
+}
+
+/// Scans the objects of a `.riv` file in a single pass, as done when the file is loaded.
+pub(crate) fn scan(data: &[u8]) -> Result<Scan, Error> {
+    let mut scanner = Scanner::default();
+
+    for object in reader::read(data)? {
+        scanner.visit(object?.type_key);
+    }
+
+    Ok(scanner.finish())
+}
+
+/// What a [`Scanner`] found in a file.
+#[derive(Debug, Default)]
+pub(crate) struct Scan {
+    pub features: Features,
+    /// The number of assets whose contents are not embedded.
+    pub referenced_assets: usize,
+    /// Whether the contents of each image and font asset are embedded, in the order in which
+    /// they are imported.
+    pub embedded_assets: Vec<bool>,
+}
+
+/// Collects the features used by a file one object at a time.
+#[derive(Debug, Default)]
+pub(crate) struct Scanner {
+    scan: Scan,
+    pending_asset: Option<u32>,
+}
+
+impl Scanner {
+    pub fn visit(&mut self, type_key: u32) {
+        if let Some(asset) = self.pending_asset.take() {
+            // Embedded assets are immediately followed by their contents.
+            self.finish_asset(asset, type_key == FILE_ASSET_CONTENTS);
+        }
+
+        let features = &mut self.scan.features;
+
+        match type_key {
+            TEXT => *features |= Features::TEXT,
+            AUDIO_EVENT => *features |= Features::AUDIO,
+            VIEW_MODEL | DATA_BIND => *features |= Features::DATA_BINDING,
+            FEATHER => *features |= Features::FEATHERING,
+            NESTED_ARTBOARD => *features |= Features::NESTED_ARTBOARDS,
+            IMAGE_ASSET | FONT_ASSET => self.pending_asset = Some(type_key),
+            AUDIO_ASSET => {
+                *features |= Features::AUDIO;
+                self.pending_asset = Some(type_key);
+            }
+            _ => (),
+        }
+    }
+
+    fn finish_asset(&mut self, type_key: u32, is_embedded: bool) {
+        if !is_embedded {
+            self.scan.referenced_assets += 1;
+        }
+
+        // Only images and fonts are listed by `rive_rs_file_assets`.
+        if matches!(type_key, IMAGE_ASSET | FONT_ASSET) {
+            self.scan.embedded_assets.push(is_embedded);
+        }
+    }
+
+    pub fn finish(mut self) -> Scan {
+        if let Some(asset) = self.pending_asset.take() {
+            self.finish_asset(asset, false);
+        }
+
+        if self.scan.referenced_assets > 0 {
+            self.scan.features |= Features::REFERENCED_ASSETS;
+        }
+
+        self.scan
+    }
+}
@@ -0,0 +1,229 @@
+use alloc::{sync::Arc, vec::Vec};
+use core::{fmt, marker::PhantomData, ptr};
+
+use crate::{
+    asset_cache::AssetCache,
+    ffi::{self},
+    renderer::Renderer,
+    theme::Theme,
+    trace::span,
+};
+
+mod assets;
+mod features;
+mod reader;
+mod validation;
+
+pub use assets::{AssetKind, Placeholder, UnresolvedAsset, UnresolvedReason};
+pub use features::Features;
+pub use validation::ValidationReport;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// Indicates that the Rive file is not supported by this runtime.
+    UnsupportedVersion,
+    /// Indicates that the there is a formatting problem in the file itself.
+    Malformed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnsupportedVersion => f.write_str("unsupported Rive version"),
+            Error::Malformed => f.write_str("file is incorrectly encoded"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[derive(Debug)]
+pub(crate) struct FileInner {
+    pub raw_file: *const ffi::File,
+    raw_factory: *mut ffi::Factory,
+}
+
+impl Drop for FileInner {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::rive_rs_file_release(self.raw_file, self.raw_factory);
+        }
+    }
+}
+
+unsafe impl Send for FileInner {}
+unsafe impl Sync for FileInner {}
+
+pub struct File<R: Renderer> {
+    inner: Arc<FileInner>,
+    features: Features,
+    unresolved_assets: Vec<UnresolvedAsset>,
+    _phantom: PhantomData<R>,
+}
+
+impl<R: Renderer> File<R> {
+    #[inline]
+    pub fn new(data: &[u8]) -> Result<Self, Error> {
+        span!("File::new");
+
+        Self::load(data, None)
+    }
+
+    /// Loads a file like [`File::new`], sharing decoded images and fonts with all other files
+    /// loaded with the same `asset_cache`.
+    #[inline]
+    pub fn with_asset_cache(data: &[u8], asset_cache: &mut AssetCache<R>) -> Result<Self, Error> {
+        span!("File::with_asset_cache");
+
+        Self::load(data, Some(asset_cache))
+    }
+
+    pub(crate) fn load(
+        data: &[u8],
+        mut asset_cache: Option<&mut dyn ffi::AssetCache<R>>,
+    ) -> Result<Self, Error> {
+        let mut result = ffi::FileResult::Success;
+        let mut raw_factory = ptr::null_mut();
+        let raw_asset_cache = asset_cache.as_mut().map_or(ptr::null_mut(), |asset_cache| {
+            asset_cache as *mut &mut dyn ffi::AssetCache<R>
+        });
+
+        let raw_file = unsafe {
+            ffi::rive_rs_file_new(
+                data.as_ptr(),
+                data.len(),
+                ffi::RendererEntries::<R>::ENTRIES as *const ffi::RendererEntries<R> as *const (),
+                raw_asset_cache as *mut (),
+                &mut result as *mut ffi::FileResult,
+                &mut raw_factory as *mut *mut ffi::Factory,
+            )
+        };
+
+        match result {
+            ffi::FileResult::Success => {
+                // Features and embedded assets are found in the same pass over the objects.
+                let scan = features::scan(data).unwrap_or_default();
+
+                Ok(Self {
+                    inner: Arc::new(FileInner {
+                        raw_file,
+                        raw_factory,
+                    }),
+                    features: scan.features,
+                    unresolved_assets: assets::unresolved(raw_file, &scan.embedded_assets),
+                    _phantom: PhantomData,
+                })
+            }
+            ffi::FileResult::UnsupportedVersion => Err(Error::UnsupportedVersion),
+            ffi::FileResult::Malformed => Err(Error::Malformed),
+        }
+    }
+
+    /// Replaces the file with a newly exported version of it in `data`, e.g. when the `.riv`
+    /// file on disk changed.
+    ///
+    /// Artboards and scenes instantiated before the reload keep using the previous version. Use
+    /// [`Scene::migrate_to`](crate::scene::Scene::migrate_to) to move them over to artboards
+    /// instantiated afterwards. Themes applied with [`File::apply_theme`] need to be applied
+    /// again. On error, the file is left unchanged.
+    ///
+    /// The new version does not share assets with other files; use
+    /// [`File::reload_with_asset_cache`] for files loaded with [`File::with_asset_cache`].
+    pub fn reload(&mut self, data: &[u8]) -> Result<(), Error> {
+        span!("File::reload");
+
+        *self = Self::load(data, None)?;
+
+        Ok(())
+    }
+
+    /// Reloads the file like [`File::reload`], sharing decoded images and fonts with all other
+    /// files loaded with the same `asset_cache`.
+    ///
+    /// Assets that did not change are taken from the cache instead of being decoded again.
+    pub fn reload_with_asset_cache(
+        &mut self,
+        data: &[u8],
+        asset_cache: &mut AssetCache<R>,
+    ) -> Result<(), Error> {
+        span!("File::reload_with_asset_cache");
+
+        *self = Self::load(data, Some(asset_cache))?;
+
+        Ok(())
+    }
+
+    /// Checks `data` for problems without importing it, e.g. to reject or flag files in a
+    /// content pipeline.
+    ///
+    /// Returns an error if the file would fail to load with [`File::new`].
+    #[inline]
+    pub fn validate(data: &[u8]) -> Result<ValidationReport, Error> {
+        span!("File::validate");
+
+        ValidationReport::scan(data)
+    }
+
+    /// Returns the features used by the file, including the ones this runtime doesn't support.
+    ///
+    /// Objects of unsupported features are skipped when the file is loaded, so hosts can use this
+    /// to warn about files that would otherwise render incompletely.
+    #[inline]
+    pub fn features(&self) -> Features {
+        self.features
+    }
+
+    /// Returns the images and fonts of the file that failed to resolve when it was loaded.
+    ///
+    /// The file still loads and plays without them, with each asset's
+    /// [`placeholder`](UnresolvedAsset::placeholder) drawn in its place, so hosts can use this to
+    /// report missing or broken assets instead of rendering incompletely without notice.
+    #[inline]
+    pub fn unresolved_assets(&self) -> &[UnresolvedAsset] {
+        &self.unresolved_assets
+    }
+
+    /// Recolors every artboard of the file with `theme`.
+    ///
+    /// Only artboards instantiated after this call are affected; use
+    /// [`Artboard::apply_theme`](crate::Artboard::apply_theme) for existing ones.
+    #[inline]
+    pub fn apply_theme(&mut self, theme: &Theme) {
+        unsafe {
+            ffi::rive_rs_file_remap_colors(
+                self.inner.raw_file,
+                theme.from().as_ptr(),
+                theme.to().as_ptr(),
+                theme.len(),
+            );
+        }
+    }
+
+    /// Returns the underlying `rive::File`.
+    ///
+    /// There is no `from_raw` counterpart, since files need to be imported with this crate's
+    /// factory in order to be drawn with `R`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid for as long as `self` or any of the artboards instantiated from
+    /// it are alive. It must not be released.
+    #[inline]
+    pub unsafe fn as_raw(&self) -> *const ffi::File {
+        self.inner.raw_file
+    }
+
+    pub(crate) fn as_inner(&self) -> &Arc<FileInner> {
+        &self.inner
+    }
+}
+
+unsafe impl<R: Renderer> Send for File<R> {}
+unsafe impl<R: Renderer> Sync for File<R> {}
+
+impl<R: Renderer> fmt::Debug for File<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("File").finish()
+    }
+}
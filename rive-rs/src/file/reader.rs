@@ -0,0 +1,212 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::ffi;
+
+use super::Error;
+
+const FINGERPRINT: &[u8] = b"RIVE";
+
+/// Backing type of a property, as stored in the file's table of contents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FieldType {
+    Uint,
+    Bytes,
+    Float,
+    Color,
+}
+
+impl FieldType {
+    fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(Self::Uint),
+            1 => Some(Self::Bytes),
+            2 => Some(Self::Float),
+            3 => Some(Self::Color),
+            _ => None,
+        }
+    }
+}
+
+/// An object as stored in the file, before being imported by the runtime.
+#[derive(Clone, Debug)]
+pub(crate) struct Object {
+    pub type_key: u32,
+}
+
+#[derive(Clone, Debug)]
+struct Header {
+    field_types: BTreeMap<u32, FieldType>,
+}
+
+impl Header {
+    /// Looks up the type of a property in the table of contents, falling back to the properties
+    /// known by the runtime.
+    fn field_type(&self, property_key: u32) -> Option<FieldType> {
+        self.field_types.get(&property_key).copied().or_else(|| {
+            u32::try_from(unsafe { ffi::rive_rs_property_field_id(property_key) })
+                .ok()
+                .and_then(FieldType::from_id)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Reader<'d> {
+    data: &'d [u8],
+}
+
+impl<'d> Reader<'d> {
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'d [u8], Error> {
+        if len > self.data.len() {
+            return Err(Error::Malformed);
+        }
+
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+
+        Ok(bytes)
+    }
+
+    fn read_var_uint(&mut self) -> Result<u64, Error> {
+        let mut result = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.read_bytes(1)?[0];
+
+            if shift >= u64::BITS {
+                return Err(Error::Malformed);
+            }
+
+            result |= u64::from(byte & 0x7F) << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn read_var_u32(&mut self) -> Result<u32, Error> {
+        u32::try_from(self.read_var_uint()?).map_err(|_| Error::Malformed)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn skip_value(&mut self, field_type: FieldType) -> Result<(), Error> {
+        match field_type {
+            FieldType::Uint => {
+                self.read_var_uint()?;
+            }
+            FieldType::Bytes => {
+                let len = usize::try_from(self.read_var_uint()?).map_err(|_| Error::Malformed)?;
+                self.read_bytes(len)?;
+            }
+            FieldType::Float | FieldType::Color => {
+                self.read_u32()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the header of a `.riv` file without importing it, returning an iterator over the objects
+/// stored in the file.
+pub(crate) fn read(data: &[u8]) -> Result<Objects<'_>, Error> {
+    let mut reader = Reader { data };
+
+    if reader.read_bytes(FINGERPRINT.len())? != FINGERPRINT {
+        return Err(Error::Malformed);
+    }
+
+    let major_version = reader.read_var_u32()?;
+    let minor_version = reader.read_var_u32()?;
+    let _file_id = reader.read_var_u32()?;
+
+    let mut property_keys = Vec::new();
+    loop {
+        match reader.read_var_u32()? {
+            0 => break,
+            property_key => property_keys.push(property_key),
+        }
+    }
+
+    // Field types are packed as 2 bits per property in little-endian 32-bit words, with only
+    // the 8 least significant bits of every word in use.
+    let mut field_types = BTreeMap::new();
+    let mut word = 0;
+    for (i, property_key) in property_keys.into_iter().enumerate() {
+        if i % 4 == 0 {
+            word = reader.read_u32()?;
+        }
+
+        let id = (word >> (i % 4 * 2)) & 0b11;
+        field_types.insert(
+            property_key,
+            FieldType::from_id(id).ok_or(Error::Malformed)?,
+        );
+    }
+
+    Ok(Objects {
+        major_version,
+        minor_version,
+        reader,
+        header: Header { field_types },
+    })
+}
+
+/// Iterator over the objects of a `.riv` file, created by [`read`]. Stops after the first
+/// malformed object.
+#[derive(Clone, Debug)]
+pub(crate) struct Objects<'d> {
+    pub major_version: u32,
+    pub minor_version: u32,
+    reader: Reader<'d>,
+    header: Header,
+}
+
+impl<'d> Objects<'d> {
+    fn read_object(&mut self) -> Result<Object, Error> {
+        let type_key = self.reader.read_var_u32()?;
+
+        loop {
+            let property_key = self.reader.read_var_u32()?;
+            if property_key == 0 {
+                break;
+            }
+
+            let field_type = self
+                .header
+                .field_type(property_key)
+                .ok_or(Error::Malformed)?;
+            self.reader.skip_value(field_type)?;
+        }
+
+        Ok(Object { type_key })
+    }
+}
+
+impl<'d> Iterator for Objects<'d> {
+    type Item = Result<Object, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.is_empty() {
+            return None;
+        }
+
+        let object = self.read_object();
+        if object.is_err() {
+            self.reader.data = &[];
+        }
+
+        Some(object)
+    }
+}
@@ -0,0 +1,83 @@
+use alloc::collections::BTreeMap;
+
+use crate::ffi;
+
+use super::{
+    features::{Scan, Scanner},
+    reader, Error, Features,
+};
+
+/// Problems found in a `.riv` file by [`File::validate`](crate::File::validate).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ValidationReport {
+    /// The major `.riv` format version of the file.
+    pub major_version: u32,
+    /// The minor `.riv` format version of the file.
+    pub minor_version: u32,
+    /// The features used by the file.
+    pub features: Features,
+    /// The features used by the file that this build of the runtime can't render, e.g. text
+    /// without the `text` feature.
+    pub unsupported_features: Features,
+    /// The number of objects of every type key unknown to the runtime, which are skipped when
+    /// the file is loaded.
+    pub unknown_types: BTreeMap<u32, usize>,
+    /// The number of assets whose contents are not embedded in the file and need to be provided
+    /// separately.
+    pub referenced_assets: usize,
+}
+
+impl ValidationReport {
+    pub(crate) fn scan(data: &[u8]) -> Result<Self, Error> {
+        let objects = reader::read(data)?;
+
+        if objects.major_version != unsafe { ffi::rive_rs_file_major_version() } {
+            return Err(Error::UnsupportedVersion);
+        }
+
+        let major_version = objects.major_version;
+        let minor_version = objects.minor_version;
+
+        let mut scanner = Scanner::default();
+        let mut unknown_types = BTreeMap::new();
+        // Checking a type key instantiates an object of that type, so it's only done once per key.
+        let mut known_types = BTreeMap::new();
+
+        for object in objects {
+            let type_key = object?.type_key;
+
+            let is_known = *known_types
+                .entry(type_key)
+                .or_insert_with(|| unsafe { ffi::rive_rs_is_type_key_known(type_key) });
+
+            if !is_known {
+                *unknown_types.entry(type_key).or_default() += 1;
+            }
+
+            scanner.visit(type_key);
+        }
+
+        let Scan {
+            features,
+            referenced_assets,
+            ..
+        } = scanner.finish();
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            features,
+            unsupported_features: features & Features::unsupported(),
+            unknown_types,
+            referenced_assets,
+        })
+    }
+
+    /// Returns `true` if the file should render exactly as designed with this build of the
+    /// runtime, provided its referenced assets are supplied.
+    #[inline]
+    pub fn is_fully_supported(&self) -> bool {
+        self.unsupported_features.is_empty() && self.unknown_types.is_empty()
+    }
+}
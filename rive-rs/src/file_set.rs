@@ -0,0 +1,155 @@
+use alloc::{collections::BTreeMap, string::String};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::asset_cache::SharedAssetCache;
+use crate::{
+    asset_cache::AssetCache,
+    file::{Error, File},
+    renderer::Renderer,
+};
+
+/// A collection of files looked up by logical name, e.g. a library of micro-interactions.
+///
+/// Files inserted one at a time share a single [`AssetCache`], so images and fonts used by
+/// several of them are only decoded once.
+pub struct FileSet<R: Renderer> {
+    files: BTreeMap<String, File<R>>,
+    asset_cache: AssetCache<R>,
+}
+
+impl<R: Renderer> FileSet<R> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads `data` and stores it under `name`, replacing any file already stored under it.
+    pub fn insert(&mut self, name: impl Into<String>, data: &[u8]) -> Result<(), Error> {
+        let file = File::with_asset_cache(data, &mut self.asset_cache)?;
+        self.files.insert(name.into(), file);
+
+        Ok(())
+    }
+
+    /// Loads all `files` in parallel and stores them under their names, returning the names and
+    /// errors of the ones that failed to load.
+    ///
+    /// Files loaded in parallel share the set's [`AssetCache`] like files inserted one at a time.
+    /// The cache is only locked to look assets up and insert them, so assets are still decoded in
+    /// parallel.
+    #[cfg(feature = "std")]
+    pub fn insert_parallel<'d, I>(&mut self, files: I) -> Vec<(String, Error)>
+    where
+        I: IntoIterator<Item = (String, &'d [u8])>,
+        R::Image: Send + Sync,
+    {
+        let files: Vec<_> = files.into_iter().collect();
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        let chunk_size = files.len() / threads + 1;
+        let asset_cache = std::sync::Mutex::new(&mut self.asset_cache);
+
+        let loaded: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = files
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    let mut asset_cache = SharedAssetCache(&asset_cache);
+
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|(name, data)| {
+                                (name.clone(), File::<R>::load(data, Some(&mut asset_cache)))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("file loading thread panicked"))
+                .collect()
+        });
+
+        let mut errors = Vec::new();
+        for (name, result) in loaded {
+            match result {
+                Ok(file) => {
+                    self.files.insert(name, file);
+                }
+                Err(error) => errors.push((name, error)),
+            }
+        }
+
+        errors
+    }
+
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&File<R>> {
+        self.files.get(name)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut File<R>> {
+        self.files.get_mut(name)
+    }
+
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.files.contains_key(name)
+    }
+
+    /// Removes the file stored under `name`. Artboards instantiated from it stay valid.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> Option<File<R>> {
+        self.files.remove(name)
+    }
+
+    /// Returns the names of all files, in sorted order.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+
+    /// Returns all files together with their names, in sorted order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &File<R>)> {
+        self.files.iter().map(|(name, file)| (name.as_str(), file))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the cache shared by the files of the set.
+    #[inline]
+    pub fn asset_cache(&self) -> &AssetCache<R> {
+        &self.asset_cache
+    }
+}
+
+impl<R: Renderer> Default for FileSet<R> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            files: BTreeMap::new(),
+            asset_cache: AssetCache::default(),
+        }
+    }
+}
+
+impl<R: Renderer> fmt::Debug for FileSet<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileSet")
+            .field("names", &self.files.keys())
+            .field("asset_cache", &self.asset_cache)
+            .finish()
+    }
+}
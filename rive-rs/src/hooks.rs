@@ -0,0 +1,233 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{any::Any, fmt, time::Duration};
+
+use crate::{
+    artboard::Artboard,
+    linear_animation::Loop,
+    renderer::Renderer,
+    scene::{Cursor, DurationKind, Scene, SceneKind, Viewport},
+};
+
+type AdvanceHook<S> = Box<dyn FnMut(&mut S, Duration) + Send + Sync>;
+type AfterAdvanceHook<S> = Box<dyn FnMut(&mut S, Duration, bool) + Send + Sync>;
+type DrawHook<R, S> = Box<dyn Fn(&S, &mut R) + Send + Sync>;
+
+/// A scene that runs hooks around every advance and draw of the scene it wraps, e.g. to tweak
+/// [`components`](crate::components) after animations were applied but before the frame is
+/// drawn, or to record timings.
+///
+/// Hooks run in the order they were added. Draw hooks run inside whatever transform and clip
+/// [`Scene::draw_in`] or [`Scene::draw_with`] set up, so they draw in artboard space.
+///
+/// `Hooked` is a [`Scene`] itself and can be used anywhere the wrapped scene could.
+/// [`Scene::as_any`] returns the wrapped scene, so [`Scene::as_state_machine`] and the like see
+/// through the wrapper.
+pub struct Hooked<R: Renderer, S: ?Sized = dyn Scene<R>> {
+    scene: Box<S>,
+    before_advance: Vec<AdvanceHook<S>>,
+    after_advance: Vec<AfterAdvanceHook<S>>,
+    before_draw: Vec<DrawHook<R, S>>,
+    after_draw: Vec<DrawHook<R, S>>,
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> Hooked<R, S> {
+    #[inline]
+    pub fn new(scene: Box<S>) -> Self {
+        Self {
+            scene,
+            before_advance: Vec::new(),
+            after_advance: Vec::new(),
+            before_draw: Vec::new(),
+            after_draw: Vec::new(),
+        }
+    }
+
+    /// Adds a hook called with the scene and the elapsed time before every advance.
+    pub fn before_advance(&mut self, hook: impl FnMut(&mut S, Duration) + Send + Sync + 'static) {
+        self.before_advance.push(Box::new(hook));
+    }
+
+    /// Adds a hook called with the scene, the elapsed time, and whether the scene is still
+    /// playing after every advance.
+    pub fn after_advance(
+        &mut self,
+        hook: impl FnMut(&mut S, Duration, bool) + Send + Sync + 'static,
+    ) {
+        self.after_advance.push(Box::new(hook));
+    }
+
+    /// Adds a hook called with the scene and the renderer before every draw.
+    pub fn before_draw(&mut self, hook: impl Fn(&S, &mut R) + Send + Sync + 'static) {
+        self.before_draw.push(Box::new(hook));
+    }
+
+    /// Adds a hook called with the scene and the renderer after every draw, so anything it draws
+    /// ends up on top of the scene.
+    pub fn after_draw(&mut self, hook: impl Fn(&S, &mut R) + Send + Sync + 'static) {
+        self.after_draw.push(Box::new(hook));
+    }
+
+    /// Removes all hooks.
+    pub fn clear_hooks(&mut self) {
+        self.before_advance.clear();
+        self.after_advance.clear();
+        self.before_draw.clear();
+        self.after_draw.clear();
+    }
+
+    #[inline]
+    pub fn scene(&self) -> &S {
+        &self.scene
+    }
+
+    /// Returns the wrapped scene. Advancing or drawing it directly doesn't run the hooks.
+    #[inline]
+    pub fn scene_mut(&mut self) -> &mut S {
+        &mut self.scene
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Box<S> {
+        self.scene
+    }
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> Scene<R> for Hooked<R, S> {
+    #[inline]
+    fn width(&self) -> f32 {
+        self.scene.width()
+    }
+
+    #[inline]
+    fn height(&self) -> f32 {
+        self.scene.height()
+    }
+
+    #[inline]
+    fn name(&self) -> &str {
+        self.scene.name()
+    }
+
+    #[inline]
+    fn kind(&self) -> SceneKind {
+        self.scene.kind()
+    }
+
+    #[inline]
+    fn r#loop(&self) -> Loop {
+        self.scene.r#loop()
+    }
+
+    #[inline]
+    fn is_translucent(&self) -> bool {
+        self.scene.is_translucent()
+    }
+
+    #[inline]
+    fn duration(&self) -> Option<Duration> {
+        self.scene.duration()
+    }
+
+    #[inline]
+    fn duration_kind(&self) -> DurationKind {
+        self.scene.duration_kind()
+    }
+
+    #[inline]
+    fn fps(&self) -> Option<u32> {
+        self.scene.fps()
+    }
+
+    #[inline]
+    fn frame_count(&self) -> Option<u32> {
+        self.scene.frame_count()
+    }
+
+    #[inline]
+    fn pointer_down(&mut self, x: f32, y: f32, viewport: &Viewport) {
+        self.scene.pointer_down(x, y, viewport);
+    }
+
+    #[inline]
+    fn pointer_move(&mut self, x: f32, y: f32, viewport: &Viewport) {
+        self.scene.pointer_move(x, y, viewport);
+    }
+
+    #[inline]
+    fn pointer_up(&mut self, x: f32, y: f32, viewport: &Viewport) {
+        self.scene.pointer_up(x, y, viewport);
+    }
+
+    #[inline]
+    fn cursor(&self, x: f32, y: f32, viewport: &Viewport) -> Cursor {
+        self.scene.cursor(x, y, viewport)
+    }
+
+    #[inline]
+    fn pointer_down_artboard(&mut self, x: f32, y: f32) {
+        self.scene.pointer_down_artboard(x, y);
+    }
+
+    #[inline]
+    fn pointer_move_artboard(&mut self, x: f32, y: f32) {
+        self.scene.pointer_move_artboard(x, y);
+    }
+
+    #[inline]
+    fn pointer_up_artboard(&mut self, x: f32, y: f32) {
+        self.scene.pointer_up_artboard(x, y);
+    }
+
+    fn advance_and_apply(&mut self, elapsed: Duration) -> bool {
+        for hook in &mut self.before_advance {
+            hook(&mut self.scene, elapsed);
+        }
+
+        let is_playing = self.scene.advance_and_apply(elapsed);
+
+        for hook in &mut self.after_advance {
+            hook(&mut self.scene, elapsed, is_playing);
+        }
+
+        is_playing
+    }
+
+    fn draw(&self, renderer: &mut R) {
+        for hook in &self.before_draw {
+            hook(&self.scene, renderer);
+        }
+
+        self.scene.draw(renderer);
+
+        for hook in &self.after_draw {
+            hook(&self.scene, renderer);
+        }
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self.scene.as_any()
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self.scene.as_any_mut()
+    }
+
+    #[inline]
+    fn artboard(&self) -> Artboard<R> {
+        self.scene.artboard()
+    }
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> fmt::Debug for Hooked<R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hooked")
+            .field("name", &self.scene.name())
+            .field("before_advance", &self.before_advance.len())
+            .field("after_advance", &self.after_advance.len())
+            .field("before_draw", &self.before_draw.len())
+            .field("after_draw", &self.after_draw.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{ops::RangeInclusive, time::Duration};
+
+use crate::{recording::InputEvent, renderer::Renderer, scene::Scene};
+
+#[derive(Clone, Debug)]
+enum Binding {
+    Trigger(String),
+    Hold(String),
+    Axis {
+        name: String,
+        range: RangeInclusive<f32>,
+    },
+}
+
+/// Declarative bindings from host keys, buttons, and axes to state machine inputs.
+///
+/// `K` is whatever the host identifies its keys and axes with, e.g. a windowing library's key
+/// codes or a gamepad library's buttons, or an enum wrapping both. Events reported with
+/// [`InputMap::press`], [`InputMap::release`], and [`InputMap::set_axis`] are queued and applied
+/// to the state machine the next time the scene is advanced with [`InputMap::advance_and_apply`],
+/// so they can be reported straight from the host's event loop.
+///
+/// Presses of keys that are already held, e.g. the key repeats sent by most platforms, are
+/// ignored, and bindings to inputs the state machine doesn't have do nothing.
+///
+/// ```
+/// # use rive_rs::InputMap;
+/// #[derive(Clone, PartialEq)]
+/// enum Key {
+///     Space,
+///     Shift,
+///     LeftStickX,
+/// }
+///
+/// let mut input_map = InputMap::new()
+///     .with_trigger(Key::Space, "jump")
+///     .with_hold(Key::Shift, "isRunning")
+///     .with_axis(Key::LeftStickX, "direction", -100.0..=100.0);
+///
+/// input_map.press(&Key::Space);
+/// input_map.set_axis(&Key::LeftStickX, 0.5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct InputMap<K> {
+    bindings: Vec<(K, Binding)>,
+    held: Vec<K>,
+    pending: Vec<InputEvent>,
+}
+
+impl<K: Clone + PartialEq> InputMap<K> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            held: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Fires the trigger input `name` every time `key` is pressed.
+    #[inline]
+    pub fn with_trigger(mut self, key: K, name: &str) -> Self {
+        self.bindings.push((key, Binding::Trigger(name.to_owned())));
+        self
+    }
+
+    /// Sets the bool input `name` to `true` while `key` is held and back to `false` when it is
+    /// released.
+    #[inline]
+    pub fn with_hold(mut self, key: K, name: &str) -> Self {
+        self.bindings.push((key, Binding::Hold(name.to_owned())));
+        self
+    }
+
+    /// Sets the number input `name` to the value of `axis`, mapped linearly from `-1.0..=1.0` to
+    /// `range`.
+    #[inline]
+    pub fn with_axis(mut self, axis: K, name: &str, range: RangeInclusive<f32>) -> Self {
+        self.bindings.push((
+            axis,
+            Binding::Axis {
+                name: name.to_owned(),
+                range,
+            },
+        ));
+        self
+    }
+
+    /// Removes all bindings of `key`, returning `false` if it wasn't bound.
+    pub fn unbind(&mut self, key: &K) -> bool {
+        let len = self.bindings.len();
+        self.bindings.retain(|(bound, _)| bound != key);
+
+        self.bindings.len() != len
+    }
+
+    /// Returns `true` if `key` is currently held.
+    #[inline]
+    pub fn is_held(&self, key: &K) -> bool {
+        self.held.contains(key)
+    }
+
+    /// Reports that `key` was pressed.
+    pub fn press(&mut self, key: &K) {
+        if self.is_held(key) {
+            return;
+        }
+
+        self.held.push(key.clone());
+
+        for (_, binding) in self.bindings.iter().filter(|(bound, _)| bound == key) {
+            match binding {
+                Binding::Trigger(name) => {
+                    self.pending.push(InputEvent::Fire { name: name.clone() })
+                }
+                Binding::Hold(name) => self.pending.push(InputEvent::SetBool {
+                    name: name.clone(),
+                    value: true,
+                }),
+                Binding::Axis { .. } => (),
+            }
+        }
+    }
+
+    /// Reports that `key` was released.
+    pub fn release(&mut self, key: &K) {
+        let Some(index) = self.held.iter().position(|held| held == key) else {
+            return;
+        };
+
+        self.held.swap_remove(index);
+
+        for (_, binding) in self.bindings.iter().filter(|(bound, _)| bound == key) {
+            if let Binding::Hold(name) = binding {
+                self.pending.push(InputEvent::SetBool {
+                    name: name.clone(),
+                    value: false,
+                });
+            }
+        }
+    }
+
+    /// Releases every held key, e.g. when the window loses focus and release events would be
+    /// missed.
+    pub fn release_all(&mut self) {
+        while let Some(key) = self.held.last().cloned() {
+            self.release(&key);
+        }
+    }
+
+    /// Reports the value of `axis`, which is clamped to `-1.0..=1.0`.
+    pub fn set_axis(&mut self, axis: &K, value: f32) {
+        let value = value.clamp(-1.0, 1.0);
+
+        for (_, binding) in self.bindings.iter().filter(|(bound, _)| bound == axis) {
+            if let Binding::Axis { name, range } = binding {
+                let t = (value + 1.0) / 2.0;
+
+                self.pending.push(InputEvent::SetNumber {
+                    name: name.clone(),
+                    value: range.start() + (range.end() - range.start()) * t,
+                });
+            }
+        }
+    }
+
+    /// Applies the events reported since the last call to `scene` without advancing it.
+    pub fn apply<R: Renderer, S: Scene<R> + ?Sized>(&mut self, scene: &mut S) {
+        for event in self.pending.drain(..) {
+            event.apply(scene);
+        }
+    }
+
+    /// Applies the events reported since the last advance to `scene` and advances it by
+    /// `elapsed`, returning whether it is still playing.
+    #[inline]
+    pub fn advance_and_apply<R: Renderer, S: Scene<R> + ?Sized>(
+        &mut self,
+        scene: &mut S,
+        elapsed: Duration,
+    ) -> bool {
+        self.apply(scene);
+        scene.advance_and_apply(elapsed)
+    }
+}
+
+impl<K: Clone + PartialEq> Default for InputMap<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::String, vec::Vec};
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Handle {
@@ -12,4 +12,12 @@ pub trait Instantiate: Sized {
     type From;
 
     fn instantiate(from: &Self::From, handle: Handle) -> Option<Self>;
+
+    /// Returns a handle to every instance that can be created from `from`, together with its
+    /// name, so that generic code can list them without knowing the concrete type.
+    ///
+    /// Returns no handles by default.
+    fn handles(_from: &Self::From) -> Vec<(Handle, String)> {
+        Vec::new()
+    }
 }
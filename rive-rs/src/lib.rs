@@ -1,4 +1,4 @@
-#![cfg_attr(not(feature = "vello"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! # Rive runtime bindings
 //!
@@ -9,6 +9,9 @@
 //! websites.
 //!
 //! [Rive]: https://rive.app
+//!
+//! Without the default `std` feature, the crate only depends on `core` and `alloc`, so it can be
+//! used on embedded targets together with a custom [`Renderer`](renderer::Renderer).
 #![cfg_attr(
     feature = "vello",
     doc = r##"
@@ -42,41 +45,92 @@ for r in 1..=5 {
 extern crate alloc;
 
 mod artboard;
-mod ffi;
+mod asset_cache;
+#[cfg(feature = "canvas")]
+pub mod canvas;
+pub mod clock;
+pub mod debug;
 mod file;
+mod file_set;
+mod hooks;
+mod input_map;
 mod instantiate;
 mod linear_animation;
-pub mod path;
+mod migrate;
 mod raw_iter;
-pub mod renderer;
+mod recording;
 pub mod scene;
+mod scene_stack;
 pub mod state_machine;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod theme;
+mod trace;
+pub mod tween;
 #[cfg(feature = "vello")]
 pub mod vello;
+mod version;
+
+use rive_rs_sys as ffi;
+
+pub use rive_rs_sys::{path, renderer};
+
+/// Opaque rive-cpp types returned by the `as_raw` escape hatches.
+///
+/// They are only meant to be passed through to C++ code that links against the same rive-cpp
+/// build.
+pub mod raw {
+    /// A `rive::ArtboardInstance`.
+    pub use crate::ffi::Artboard;
+    /// A `rive::File`.
+    pub use crate::ffi::File;
+    /// A `rive::LinearAnimationInstance`.
+    pub use crate::ffi::LinearAnimation;
+    /// A `rive::StateMachineInstance`.
+    pub use crate::ffi::StateMachine;
+}
 
 pub use crate::{
-    artboard::components,
-    file::Error,
+    artboard::{components, LineMetrics, Scenes, TextMetrics, VisibleText},
+    file::{
+        AssetKind, Error, Features, Placeholder, UnresolvedAsset, UnresolvedReason,
+        ValidationReport,
+    },
+    input_map::InputMap,
     instantiate::{Handle, Instantiate},
     linear_animation::{Direction, Loop},
-    scene::Viewport,
+    recording::{InputEvent, ParseRecordingError, Recording, Replayer, TimedEvent},
+    scene::{Cursor, DrawOptions, DurationKind, Letterbox, SceneKind, Viewport},
+    theme::Theme,
+    version::{runtime_version, RuntimeVersion},
 };
 
 #[cfg(not(feature = "vello"))]
 pub use crate::{
-    artboard::Artboard, file::File, linear_animation::LinearAnimation, scene::Scene,
+    artboard::Artboard, asset_cache::AssetCache, file::File, file_set::FileSet, hooks::Hooked,
+    linear_animation::LinearAnimation, recording::Recorder, scene::Scene, scene_stack::SceneStack,
     state_machine::StateMachine,
 };
 
 #[cfg(feature = "vello")]
 pub type Artboard = artboard::Artboard<crate::vello::Renderer>;
 #[cfg(feature = "vello")]
+pub type AssetCache = asset_cache::AssetCache<crate::vello::Renderer>;
+#[cfg(feature = "vello")]
 pub type File = file::File<crate::vello::Renderer>;
 #[cfg(feature = "vello")]
+pub type FileSet = file_set::FileSet<crate::vello::Renderer>;
+#[cfg(feature = "vello")]
+pub type Hooked = hooks::Hooked<crate::vello::Renderer, dyn Scene>;
+#[cfg(feature = "vello")]
 pub type LinearAnimation = linear_animation::LinearAnimation<crate::vello::Renderer>;
 #[cfg(feature = "vello")]
+pub type Recorder = recording::Recorder<crate::vello::Renderer, dyn Scene>;
+#[cfg(feature = "vello")]
 pub type StateMachine = state_machine::StateMachine<crate::vello::Renderer>;
 #[cfg(feature = "vello")]
+pub type SceneStack = scene_stack::SceneStack<crate::vello::Renderer, dyn Scene>;
+#[cfg(feature = "vello")]
 pub use crate::vello::Renderer;
 
 #[cfg(feature = "vello")]
@@ -84,6 +138,30 @@ pub trait Scene: scene::Scene<crate::vello::Renderer> {}
 #[cfg(feature = "vello")]
 impl<T: scene::Scene<crate::vello::Renderer>> Scene for T {}
 
+#[cfg(feature = "vello")]
+impl From<Artboard> for Box<dyn Scene> {
+    #[inline]
+    fn from(artboard: Artboard) -> Self {
+        Box::new(artboard)
+    }
+}
+
+#[cfg(feature = "vello")]
+impl From<LinearAnimation> for Box<dyn Scene> {
+    #[inline]
+    fn from(linear_animation: LinearAnimation) -> Self {
+        Box::new(linear_animation)
+    }
+}
+
+#[cfg(feature = "vello")]
+impl From<StateMachine> for Box<dyn Scene> {
+    #[inline]
+    fn from(state_machine: StateMachine) -> Self {
+        Box::new(state_machine)
+    }
+}
+
 #[cfg(feature = "vello")]
 impl Instantiate for Box<dyn Scene> {
     type From = Artboard;
@@ -95,4 +173,9 @@ impl Instantiate for Box<dyn Scene> {
                 LinearAnimation::instantiate(from, handle).map(|la| Box::new(la) as Box<dyn Scene>)
             })
     }
+
+    #[inline]
+    fn handles(from: &Self::From) -> Vec<(Handle, String)> {
+        <Box<dyn scene::Scene<crate::vello::Renderer>>>::handles(from)
+    }
 }
@@ -1,5 +1,5 @@
-use alloc::sync::Arc;
-use core::{fmt, marker::PhantomData, ptr::NonNull, time::Duration};
+use alloc::{borrow::ToOwned, string::String, sync::Arc, vec::Vec};
+use core::{fmt, marker::PhantomData, ops::Range, ptr::NonNull, time::Duration};
 
 use crate::{
     artboard::{Artboard, ArtboardInner},
@@ -7,6 +7,7 @@ use crate::{
     instantiate::{Handle, Instantiate},
     renderer::Renderer,
     scene::impl_scene,
+    trace::span,
 };
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -15,21 +16,23 @@ pub enum Direction {
     Backwards,
 }
 
-#[repr(u32)]
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum Loop {
-    /// Play until the duration or end of work area of the animation.
-    OneShot = 0,
-    /// Play until the duration or end of work area of the animation and
-    /// then go back to the start (0 seconds).
-    Loop = 1,
-    /// Play to the end of the duration/work area and then play back.
-    PingPong = 2,
+pub use crate::ffi::Loop;
+
+/// Work area set with [`LinearAnimation::set_work_area`], together with the state of the last
+/// advance within it, which rive-cpp only tracks for the animation's own work area.
+#[derive(Clone, Debug)]
+struct WorkArea {
+    frames: Range<u32>,
+    did_loop: bool,
+    is_done: bool,
 }
 
 pub struct LinearAnimation<R: Renderer> {
     artboard: Arc<ArtboardInner>,
     raw_linear_animation: *mut ffi::LinearAnimation,
+    loop_count: u64,
+    loop_override: Option<Loop>,
+    work_area: Option<WorkArea>,
     _phantom: PhantomData<R>,
 }
 
@@ -38,6 +41,8 @@ impl<R: Renderer> Instantiate for LinearAnimation<R> {
 
     #[inline]
     fn instantiate(artboard: &Self::From, handle: Handle) -> Option<Self> {
+        span!("LinearAnimation::instantiate");
+
         let mut raw_linear_animation: Option<NonNull<ffi::LinearAnimation>> = None;
 
         match handle {
@@ -68,9 +73,21 @@ impl<R: Renderer> Instantiate for LinearAnimation<R> {
         raw_linear_animation.map(|raw_linear_animation| LinearAnimation {
             artboard: artboard.as_inner().clone(),
             raw_linear_animation: raw_linear_animation.as_ptr(),
+            loop_count: 0,
+            loop_override: None,
+            work_area: None,
             _phantom: PhantomData,
         })
     }
+
+    fn handles(artboard: &Self::From) -> Vec<(Handle, String)> {
+        (0..artboard.linear_animation_count())
+            .map(|index| {
+                let name = artboard.linear_animation_name(index).to_owned();
+                (Handle::Index(index), name)
+            })
+            .collect()
+    }
 }
 
 impl<R: Renderer> LinearAnimation<R> {
@@ -82,10 +99,79 @@ impl<R: Renderer> LinearAnimation<R> {
         Artboard::from_inner(self.artboard.clone())
     }
 
+    /// Takes ownership of a `rive::LinearAnimationInstance` instantiated from `artboard`.
+    ///
+    /// # Safety
+    ///
+    /// `raw_linear_animation` must be a valid, heap-allocated instance created from `artboard`'s
+    /// `rive::ArtboardInstance` which is not owned by anything else. It is deleted when the
+    /// returned value is dropped.
+    #[inline]
+    pub unsafe fn from_raw(
+        artboard: &Artboard<R>,
+        raw_linear_animation: *mut ffi::LinearAnimation,
+    ) -> Self {
+        Self {
+            artboard: artboard.as_inner().clone(),
+            raw_linear_animation,
+            loop_count: 0,
+            loop_override: None,
+            work_area: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the underlying `rive::LinearAnimationInstance`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid for as long as `self` is alive. It must not be released.
+    #[inline]
+    pub unsafe fn as_raw(&self) -> *mut ffi::LinearAnimation {
+        self.raw_linear_animation
+    }
+
     fn raw_scene(&self) -> *mut ffi::Scene {
         self.raw_linear_animation as *mut ffi::Scene
     }
 
+    /// Duplicates the artboard this animation is playing on with [`Artboard::duplicate`],
+    /// together with the animation itself, keeping its time, direction, loop mode, loop count,
+    /// and work area.
+    pub fn duplicate(&self) -> Self {
+        let artboard = self.artboard().duplicate();
+        let raw_linear_animation = unsafe {
+            ffi::rive_rs_linear_animation_duplicate(
+                self.raw_linear_animation,
+                artboard.as_inner().raw_artboard,
+            )
+        };
+
+        Self {
+            artboard: artboard.as_inner().clone(),
+            raw_linear_animation,
+            loop_count: self.loop_count,
+            loop_override: self.loop_override,
+            work_area: self.work_area.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Carries the loop mode set with [`LinearAnimation::set_loop`], the work area set with
+    /// [`LinearAnimation::set_work_area`] and the loop count of `other` over, as done by
+    /// [`Scene::migrate_to`](crate::scene::Scene::migrate_to).
+    pub(crate) fn copy_overrides(&mut self, other: &Self) {
+        if let Some(r#loop) = other.loop_override {
+            self.set_loop(r#loop);
+        }
+
+        if let Some(work_area) = &other.work_area {
+            self.set_work_area(Some(work_area.frames.clone()));
+        }
+
+        self.loop_count = other.loop_count;
+    }
+
     pub fn time(&self) -> Duration {
         Duration::from_secs_f32(unsafe {
             ffi::rive_rs_linear_animation_time(self.raw_linear_animation)
@@ -98,6 +184,10 @@ impl<R: Renderer> LinearAnimation<R> {
         }
     }
 
+    /// Returns the direction the animation is currently playing in.
+    ///
+    /// Ping-pong animations flip direction at every loop boundary, so this tells whether they
+    /// are in their forward or backward phase.
     pub fn direction(&self) -> Direction {
         match unsafe { ffi::rive_rs_linear_animation_is_forwards(self.raw_linear_animation) } {
             true => Direction::Forwards,
@@ -115,27 +205,197 @@ impl<R: Renderer> LinearAnimation<R> {
     }
 
     pub fn advance(&mut self, elapsed: Duration) -> bool {
-        unsafe {
-            ffi::rive_rs_linear_animation_advance(self.raw_linear_animation, elapsed.as_secs_f32())
+        let is_playing = match &mut self.work_area {
+            Some(work_area) => {
+                let is_playing = unsafe {
+                    ffi::rive_rs_linear_animation_advance_in(
+                        self.raw_linear_animation,
+                        elapsed.as_secs_f32(),
+                        work_area.frames.start,
+                        work_area.frames.end,
+                        &mut work_area.did_loop,
+                    )
+                };
+                work_area.is_done = !is_playing;
+
+                is_playing
+            }
+            None => unsafe {
+                ffi::rive_rs_linear_animation_advance(
+                    self.raw_linear_animation,
+                    elapsed.as_secs_f32(),
+                )
+            },
+        };
+
+        self.count_loop();
+
+        is_playing
+    }
+
+    /// Advances and applies the animation within its work area if one was set with
+    /// [`LinearAnimation::set_work_area`], returning `None` otherwise.
+    fn advance_and_apply_in_work_area(&mut self, elapsed: Duration) -> Option<bool> {
+        let work_area = self.work_area.as_mut()?;
+
+        let mut did_loop = false;
+        let is_playing = unsafe {
+            ffi::rive_rs_linear_animation_advance_and_apply_in(
+                self.raw_linear_animation,
+                self.artboard.raw_artboard,
+                elapsed.as_secs_f32(),
+                work_area.frames.start,
+                work_area.frames.end,
+                &mut did_loop,
+            )
+        };
+        work_area.did_loop = did_loop;
+        work_area.is_done = !is_playing;
+
+        Some(is_playing)
+    }
+
+    fn count_loop(&mut self) {
+        if self.did_loop() {
+            self.loop_count += 1;
         }
     }
 
+    /// Returns the number of times the animation reached a loop boundary since it was
+    /// instantiated, i.e. the number of advances after which [`LinearAnimation::did_loop`]
+    /// returned `true`.
+    ///
+    /// For ping-pong animations, even counts are forward phases and odd ones backward phases of
+    /// an animation that started playing forwards. An advance spanning more than one loop is
+    /// only counted once, so keep the elapsed time below the animation's duration when the count
+    /// needs to be exact.
+    #[inline]
+    pub fn loop_count(&self) -> u64 {
+        self.loop_count
+    }
+
+    /// Applies the animation at its current time to its artboard, blending it with the current
+    /// pose by `mix`, between `0.0` and `1.0`.
+    ///
+    /// Several animations instantiated from the same artboard can be applied one after the other
+    /// to blend them, followed by an [`Artboard::advance`] to update the pose.
     pub fn apply(&mut self, mix: f32) {
         unsafe { ffi::rive_rs_linear_animation_apply(self.raw_linear_animation, mix) }
     }
 
+    /// Poses the artboard at `time` without changing the animation's own time, e.g. to render a
+    /// specific frame without disturbing playback. Call [`Artboard::advance`] afterwards to update
+    /// the pose.
+    #[inline]
+    pub fn apply_at(&mut self, time: Duration) {
+        self.apply_mix(time, 1.0);
+    }
+
+    /// Applies the animation at `time` to its artboard, blending it with the current pose by
+    /// `mix`, between `0.0` and `1.0`. The animation's own time is left untouched.
+    ///
+    /// Animations instantiated from the same [`Artboard`] share it, so applying several of them
+    /// with different weights before [`Artboard::advance`] blends their poses, e.g. to cross-fade
+    /// between an idle and a walk cycle.
+    pub fn apply_mix(&mut self, time: Duration, mix: f32) {
+        unsafe {
+            ffi::rive_rs_linear_animation_apply_at(
+                self.raw_linear_animation,
+                self.raw_artboard(),
+                time.as_secs_f32(),
+                mix,
+            );
+        }
+    }
+
     pub fn did_loop(&self) -> bool {
-        unsafe { ffi::rive_rs_linear_animation_did_loop(self.raw_linear_animation) }
+        match &self.work_area {
+            Some(work_area) => work_area.did_loop,
+            None => unsafe { ffi::rive_rs_linear_animation_did_loop(self.raw_linear_animation) },
+        }
     }
 
+    /// Overrides the loop mode the animation was exported with, e.g. to make a one-shot loop.
+    /// The current mode is returned by [`Scene::r#loop`](crate::scene::Scene::r#loop).
     pub fn set_loop(&mut self, r#loop: Loop) {
+        self.loop_override = Some(r#loop);
+
         unsafe {
             ffi::rive_rs_linear_animation_set_loop(self.raw_linear_animation, r#loop);
         }
     }
 
     pub fn is_done(&self) -> bool {
-        unsafe { ffi::rive_rs_linear_animation_is_done(self.raw_linear_animation) }
+        match &self.work_area {
+            Some(work_area) => work_area.is_done,
+            None => unsafe { ffi::rive_rs_linear_animation_is_done(self.raw_linear_animation) },
+        }
+    }
+
+    /// Returns the range of frames that plays, if the animation is restricted to a work area,
+    /// either the one set with [`LinearAnimation::set_work_area`] or the one it was exported with.
+    pub fn work_area(&self) -> Option<Range<u32>> {
+        if let Some(work_area) = &self.work_area {
+            return Some(work_area.frames.clone());
+        }
+
+        let mut start = 0;
+        let mut end = 0;
+
+        unsafe {
+            ffi::rive_rs_linear_animation_work_area(self.raw_linear_animation, &mut start, &mut end)
+        }
+        .then_some(start..end)
+    }
+
+    /// Restricts playback of this instance to the `frames` sub-range of the animation, e.g. to
+    /// only play an intro or to loop a single section, or goes back to the work area the
+    /// animation was exported with on `None`.
+    ///
+    /// `frames` is clamped to the animation's duration, and its bounds are swapped if `start`
+    /// is past `end`. An empty range holds the animation on its start frame. If the current time
+    /// is outside of the range, it moves to the frame playback starts from: the start when
+    /// playing forwards and the end when playing backwards. Other instances of the animation are
+    /// not affected.
+    pub fn set_work_area(&mut self, frames: Option<Range<u32>>) {
+        self.work_area = frames.map(|frames| {
+            let duration = self.duration_frames();
+            let start = frames.start.min(duration);
+            let end = frames.end.min(duration);
+
+            WorkArea {
+                frames: start.min(end)..start.max(end),
+                did_loop: false,
+                is_done: false,
+            }
+        });
+
+        if let Some(work_area) = &self.work_area {
+            unsafe {
+                ffi::rive_rs_linear_animation_enter_range(
+                    self.raw_linear_animation,
+                    work_area.frames.start,
+                    work_area.frames.end,
+                );
+            }
+        }
+    }
+
+    /// Returns the duration of the whole animation in frames, ignoring any work area.
+    fn duration_frames(&self) -> u32 {
+        unsafe { ffi::rive_rs_linear_animation_duration(self.raw_linear_animation) }
+    }
+
+    /// Returns the duration of the frames that play, so that it agrees with
+    /// [`Scene::frame_count`](crate::scene::Scene::frame_count) when a work area was set.
+    fn work_area_duration(&self) -> Option<Duration> {
+        let fps = unsafe { ffi::rive_rs_linear_animation_fps(self.raw_linear_animation) };
+        let frames = self
+            .work_area()
+            .unwrap_or_else(|| 0..self.duration_frames());
+
+        Duration::try_from_secs_f32(frames.end.saturating_sub(frames.start) as f32 / fps as f32)
+            .ok()
     }
 }
 
@@ -156,4 +416,24 @@ impl<R: Renderer> Drop for LinearAnimation<R> {
 unsafe impl<R: Renderer> Send for LinearAnimation<R> {}
 unsafe impl<R: Renderer> Sync for LinearAnimation<R> {}
 
-impl_scene!(LinearAnimation);
+impl_scene!(
+    LinearAnimation,
+    after_advance: count_loop,
+    advance_and_apply: advance_and_apply_in_work_area,
+    duration: work_area_duration
+    {
+        #[inline]
+        fn fps(&self) -> Option<u32> {
+            Some(unsafe { ffi::rive_rs_linear_animation_fps(self.raw_linear_animation) })
+        }
+
+        #[inline]
+        fn frame_count(&self) -> Option<u32> {
+            let frames = self
+                .work_area()
+                .unwrap_or_else(|| 0..self.duration_frames());
+
+            Some(frames.end.saturating_sub(frames.start))
+        }
+    }
+);
@@ -0,0 +1,106 @@
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use core::time::Duration;
+
+use crate::{
+    artboard::{components::TextValueRun, Artboard},
+    instantiate::{Handle, Instantiate},
+    linear_animation::LinearAnimation,
+    renderer::Renderer,
+    scene::{Scene, SceneKind},
+    state_machine::{InputValue, StateMachine},
+};
+
+/// Instantiates a scene of the same kind and name as `scene` from `artboard` and carries its
+/// state over. See [`Scene::migrate_to`].
+pub(crate) fn migrate<R: Renderer, S: Scene<R> + ?Sized>(
+    scene: &S,
+    artboard: &Artboard<R>,
+) -> Option<Box<dyn Scene<R>>> {
+    let handle = Handle::Name(scene.name().to_owned().into());
+
+    let mut migrated: Box<dyn Scene<R>> = match scene.kind() {
+        SceneKind::Artboard => Box::new(Artboard::from_inner(artboard.as_inner().clone())),
+        SceneKind::LinearAnimation => {
+            let mut linear_animation = LinearAnimation::instantiate(artboard, handle)?;
+
+            if let Some(old) = scene.as_linear_animation() {
+                linear_animation.set_direction(old.direction());
+                linear_animation.copy_overrides(old);
+                linear_animation.set_time(old.time());
+            }
+
+            Box::new(linear_animation)
+        }
+        SceneKind::StateMachine => {
+            let mut state_machine = StateMachine::instantiate(artboard, handle)?;
+
+            if let Some(old) = scene.as_state_machine() {
+                copy_inputs(old, &state_machine);
+                copy_disabled_listeners(old, &mut state_machine);
+            }
+
+            Box::new(state_machine)
+        }
+    };
+
+    copy_text_runs(&mut scene.artboard(), &mut migrated.artboard());
+
+    // Applies the copied values, which also re-enters the states they lead to.
+    migrated.advance_and_apply(Duration::ZERO);
+
+    Some(migrated)
+}
+
+fn copy_inputs<R: Renderer>(from: &StateMachine<R>, to: &StateMachine<R>) {
+    for input in from.inputs() {
+        match input.value() {
+            Some(InputValue::Bool(value)) => {
+                if let Some(mut input) = to.get_bool(input.name()) {
+                    input.set(value);
+                }
+            }
+            Some(InputValue::Number(value)) => {
+                if let Some(mut input) = to.get_number(input.name()) {
+                    input.set(value);
+                }
+            }
+            None => (),
+        }
+    }
+}
+
+fn copy_disabled_listeners<R: Renderer>(from: &StateMachine<R>, to: &mut StateMachine<R>) {
+    for index in 0..from.listener_count() {
+        let Some(name) = from.listener_name(index) else {
+            continue;
+        };
+
+        if from.is_listener_enabled(name) == Some(false) {
+            to.set_listener_enabled(name, false);
+        }
+    }
+}
+
+fn copy_text_runs<R: Renderer>(from: &mut Artboard<R>, to: &mut Artboard<R>) {
+    let texts: Vec<(String, String)> = from
+        .components()
+        .filter_map(|component| {
+            let name = component.name().to_owned();
+            let run = TextValueRun::try_from(component).ok()?;
+
+            Some((name, run.get_text().to_owned()))
+        })
+        .collect();
+
+    for component in to.components() {
+        let Some((_, text)) = texts.iter().find(|(name, _)| name == component.name()) else {
+            continue;
+        };
+
+        if let Ok(mut run) = TextValueRun::try_from(component) {
+            if run.get_text() != text {
+                run.set_text(text);
+            }
+        }
+    }
+}
@@ -0,0 +1,495 @@
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use core::{any::Any, fmt, str::FromStr, time::Duration};
+
+use crate::{
+    artboard::Artboard,
+    clock::{Clock, FixedClock},
+    linear_animation::Loop,
+    renderer::Renderer,
+    scene::{Cursor, DurationKind, Scene, SceneKind},
+};
+
+/// An input applied to a scene. Pointer positions are in artboard space, so recordings don't
+/// depend on the size of the viewport they were made in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputEvent {
+    PointerDown { x: f32, y: f32 },
+    PointerMove { x: f32, y: f32 },
+    PointerUp { x: f32, y: f32 },
+    SetBool { name: String, value: bool },
+    SetNumber { name: String, value: f32 },
+    Fire { name: String },
+}
+
+impl InputEvent {
+    /// Applies the event to `scene`, returning `false` if it's an input the scene doesn't have.
+    pub fn apply<R: Renderer, S: Scene<R> + ?Sized>(&self, scene: &mut S) -> bool {
+        match self {
+            InputEvent::PointerDown { x, y } => scene.pointer_down_artboard(*x, *y),
+            InputEvent::PointerMove { x, y } => scene.pointer_move_artboard(*x, *y),
+            InputEvent::PointerUp { x, y } => scene.pointer_up_artboard(*x, *y),
+            InputEvent::SetBool { name, value } => {
+                let Some(mut input) = scene.as_state_machine().and_then(|sm| sm.get_bool(name))
+                else {
+                    return false;
+                };
+
+                input.set(*value);
+            }
+            InputEvent::SetNumber { name, value } => {
+                let Some(mut input) = scene.as_state_machine().and_then(|sm| sm.get_number(name))
+                else {
+                    return false;
+                };
+
+                input.set(*value);
+            }
+            InputEvent::Fire { name } => {
+                return scene
+                    .as_state_machine_mut()
+                    .is_some_and(|sm| sm.trigger(name));
+            }
+        }
+
+        true
+    }
+}
+
+/// An [`InputEvent`] together with the scene time it was applied at, i.e. the sum of all the
+/// time the scene was advanced by before it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedEvent {
+    pub time: Duration,
+    pub event: InputEvent,
+}
+
+/// Error returned when parsing a [`Recording`] fails.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseRecordingError {
+    /// The 1-based number of the offending line.
+    pub line: usize,
+}
+
+impl fmt::Display for ParseRecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid input recording on line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRecordingError {}
+
+/// The inputs applied to a scene over time, as captured by a [`Recorder`] and fed back by a
+/// [`Replayer`].
+///
+/// Recordings convert to and from a line-based text format with [`ToString`] and [`FromStr`],
+/// so they can be attached to bug reports together with the `.riv` file they were made with.
+/// Times are stored as whole nanoseconds and positions in their shortest exact form, so a
+/// parsed recording is identical to the original.
+///
+/// ```
+/// # use core::time::Duration;
+/// # use rive_rs::{InputEvent, Recording, TimedEvent};
+/// let recording = Recording {
+///     step: Duration::from_millis(16),
+///     duration: Duration::from_millis(48),
+///     events: vec![
+///         TimedEvent {
+///             time: Duration::from_millis(16),
+///             event: InputEvent::PointerDown { x: 12.5, y: 40.0 },
+///         },
+///         TimedEvent {
+///             time: Duration::from_millis(32),
+///             event: InputEvent::SetBool { name: "is hovered".into(), value: true },
+///         },
+///     ],
+/// };
+///
+/// assert_eq!(recording.to_string().parse(), Ok(recording));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Recording {
+    /// The time the scene was advanced by on its first frame, used as the fixed timestep when
+    /// replaying. Recording with a [`FixedClock`] makes replays reproduce the original exactly.
+    pub step: Duration,
+    /// The total time the scene was advanced by while recording.
+    pub duration: Duration,
+    /// The events in the order they were applied.
+    pub events: Vec<TimedEvent>,
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "step {}", self.step.as_nanos())?;
+        writeln!(f, "duration {}", self.duration.as_nanos())?;
+
+        for TimedEvent { time, event } in &self.events {
+            let time = time.as_nanos();
+
+            match event {
+                InputEvent::PointerDown { x, y } => writeln!(f, "{time} pointer_down {x} {y}")?,
+                InputEvent::PointerMove { x, y } => writeln!(f, "{time} pointer_move {x} {y}")?,
+                InputEvent::PointerUp { x, y } => writeln!(f, "{time} pointer_up {x} {y}")?,
+                InputEvent::SetBool { name, value } => {
+                    writeln!(f, "{time} set_bool {value} {name}")?
+                }
+                InputEvent::SetNumber { name, value } => {
+                    writeln!(f, "{time} set_number {value} {name}")?
+                }
+                InputEvent::Fire { name } => writeln!(f, "{time} fire {name}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_duration(nanos: &str) -> Option<Duration> {
+    let nanos: u64 = nanos.parse().ok()?;
+    Some(Duration::from_nanos(nanos))
+}
+
+fn parse_event(line: &str) -> Option<TimedEvent> {
+    let (time, rest) = line.split_once(' ')?;
+    let (kind, args) = rest.split_once(' ').unwrap_or((rest, ""));
+
+    let position = || {
+        let (x, y) = args.split_once(' ')?;
+        Some((x.parse().ok()?, y.parse().ok()?))
+    };
+    let value_and_name = || args.split_once(' ');
+
+    let event = match kind {
+        "pointer_down" => position().map(|(x, y)| InputEvent::PointerDown { x, y })?,
+        "pointer_move" => position().map(|(x, y)| InputEvent::PointerMove { x, y })?,
+        "pointer_up" => position().map(|(x, y)| InputEvent::PointerUp { x, y })?,
+        "set_bool" => {
+            let (value, name) = value_and_name()?;
+            InputEvent::SetBool {
+                name: name.to_owned(),
+                value: value.parse().ok()?,
+            }
+        }
+        "set_number" => {
+            let (value, name) = value_and_name()?;
+            InputEvent::SetNumber {
+                name: name.to_owned(),
+                value: value.parse().ok()?,
+            }
+        }
+        "fire" => InputEvent::Fire {
+            name: args.to_owned(),
+        },
+        _ => return None,
+    };
+
+    Some(TimedEvent {
+        time: parse_duration(time)?,
+        event,
+    })
+}
+
+impl FromStr for Recording {
+    type Err = ParseRecordingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut recording = Recording::default();
+
+        for (index, line) in s.lines().enumerate() {
+            let error = ParseRecordingError { line: index + 1 };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(step) = line.strip_prefix("step ") {
+                recording.step = parse_duration(step).ok_or(error)?;
+            } else if let Some(duration) = line.strip_prefix("duration ") {
+                recording.duration = parse_duration(duration).ok_or(error)?;
+            } else {
+                recording.events.push(parse_event(line).ok_or(error)?);
+            }
+        }
+
+        Ok(recording)
+    }
+}
+
+/// A scene that records the pointer events and inputs applied to the scene it wraps.
+///
+/// Pointer events sent to the recorder, in viewport or artboard space, are recorded in artboard
+/// space. Inputs are only recorded when set through [`Recorder::set_bool`],
+/// [`Recorder::set_number`], and [`Recorder::fire`]; changes made by listeners are reproduced by
+/// replaying the pointer events that caused them.
+pub struct Recorder<R: Renderer, S: ?Sized = dyn Scene<R>> {
+    scene: Box<S>,
+    recording: Recording,
+    _phantom: core::marker::PhantomData<fn() -> R>,
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> Recorder<R, S> {
+    #[inline]
+    pub fn new(scene: Box<S>) -> Self {
+        Self {
+            scene,
+            recording: Recording::default(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    fn record(&mut self, event: InputEvent) {
+        self.recording.events.push(TimedEvent {
+            time: self.recording.duration,
+            event,
+        });
+    }
+
+    fn apply_and_record(&mut self, event: InputEvent) -> bool {
+        let is_applied = event.apply(&mut *self.scene);
+
+        if is_applied {
+            self.record(event);
+        }
+
+        is_applied
+    }
+
+    /// Sets the bool input named `name` of a state machine and records it, returning `false` if
+    /// there is no such input.
+    pub fn set_bool(&mut self, name: &str, value: bool) -> bool {
+        self.apply_and_record(InputEvent::SetBool {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    /// Sets the number input named `name` of a state machine and records it, returning `false`
+    /// if there is no such input.
+    pub fn set_number(&mut self, name: &str, value: f32) -> bool {
+        self.apply_and_record(InputEvent::SetNumber {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    /// Fires the trigger input named `name` of a state machine and records it, returning
+    /// `false` if there is no such input.
+    pub fn fire(&mut self, name: &str) -> bool {
+        self.apply_and_record(InputEvent::Fire {
+            name: name.to_owned(),
+        })
+    }
+
+    #[inline]
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Returns the recording made so far and starts a new one from the scene's current state.
+    #[inline]
+    pub fn take_recording(&mut self) -> Recording {
+        core::mem::take(&mut self.recording)
+    }
+
+    #[inline]
+    pub fn scene(&self) -> &S {
+        &self.scene
+    }
+
+    /// Returns the wrapped scene. Inputs applied to it directly are not recorded.
+    #[inline]
+    pub fn scene_mut(&mut self) -> &mut S {
+        &mut self.scene
+    }
+
+    #[inline]
+    pub fn into_parts(self) -> (Box<S>, Recording) {
+        (self.scene, self.recording)
+    }
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> Scene<R> for Recorder<R, S> {
+    #[inline]
+    fn width(&self) -> f32 {
+        self.scene.width()
+    }
+
+    #[inline]
+    fn height(&self) -> f32 {
+        self.scene.height()
+    }
+
+    #[inline]
+    fn name(&self) -> &str {
+        self.scene.name()
+    }
+
+    #[inline]
+    fn kind(&self) -> SceneKind {
+        self.scene.kind()
+    }
+
+    #[inline]
+    fn r#loop(&self) -> Loop {
+        self.scene.r#loop()
+    }
+
+    #[inline]
+    fn is_translucent(&self) -> bool {
+        self.scene.is_translucent()
+    }
+
+    #[inline]
+    fn duration(&self) -> Option<Duration> {
+        self.scene.duration()
+    }
+
+    #[inline]
+    fn duration_kind(&self) -> DurationKind {
+        self.scene.duration_kind()
+    }
+
+    #[inline]
+    fn fps(&self) -> Option<u32> {
+        self.scene.fps()
+    }
+
+    #[inline]
+    fn frame_count(&self) -> Option<u32> {
+        self.scene.frame_count()
+    }
+
+    #[inline]
+    fn cursor(&self, x: f32, y: f32, viewport: &crate::scene::Viewport) -> Cursor {
+        self.scene.cursor(x, y, viewport)
+    }
+
+    fn pointer_down_artboard(&mut self, x: f32, y: f32) {
+        self.scene.pointer_down_artboard(x, y);
+        self.record(InputEvent::PointerDown { x, y });
+    }
+
+    fn pointer_move_artboard(&mut self, x: f32, y: f32) {
+        self.scene.pointer_move_artboard(x, y);
+        self.record(InputEvent::PointerMove { x, y });
+    }
+
+    fn pointer_up_artboard(&mut self, x: f32, y: f32) {
+        self.scene.pointer_up_artboard(x, y);
+        self.record(InputEvent::PointerUp { x, y });
+    }
+
+    fn advance_and_apply(&mut self, elapsed: Duration) -> bool {
+        if self.recording.step.is_zero() {
+            self.recording.step = elapsed;
+        }
+
+        self.recording.duration += elapsed;
+        self.scene.advance_and_apply(elapsed)
+    }
+
+    #[inline]
+    fn draw(&self, renderer: &mut R) {
+        self.scene.draw(renderer);
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self.scene.as_any()
+    }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self.scene.as_any_mut()
+    }
+
+    #[inline]
+    fn artboard(&self) -> Artboard<R> {
+        self.scene.artboard()
+    }
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> fmt::Debug for Recorder<R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder")
+            .field("name", &self.scene.name())
+            .field("recording", &self.recording)
+            .finish()
+    }
+}
+
+/// Feeds a [`Recording`] back into a scene with a fixed timestep.
+///
+/// Every [`Replayer::advance`] first applies the events recorded up to the current time and then
+/// advances the scene by [`Recording::step`], so replaying into a freshly instantiated scene
+/// gives the same result on every run and every machine.
+#[derive(Clone, Debug)]
+pub struct Replayer {
+    recording: Recording,
+    clock: FixedClock,
+    time: Duration,
+    next_event: usize,
+}
+
+impl Replayer {
+    pub fn new(recording: Recording) -> Self {
+        let clock = FixedClock::new(recording.step);
+
+        Self {
+            recording,
+            clock,
+            time: Duration::ZERO,
+            next_event: 0,
+        }
+    }
+
+    /// Returns the scene time replayed so far.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns `true` once all events were applied and the scene was advanced by the recording's
+    /// whole duration.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.next_event == self.recording.events.len() && self.time >= self.recording.duration
+    }
+
+    fn apply_due_events<R: Renderer, S: Scene<R> + ?Sized>(&mut self, scene: &mut S) {
+        while let Some(TimedEvent { time, event }) = self.recording.events.get(self.next_event) {
+            if *time > self.time {
+                break;
+            }
+
+            event.apply(scene);
+            self.next_event += 1;
+        }
+    }
+
+    /// Applies the events that are due and advances `scene` by one step, returning `false` once
+    /// the replay is finished.
+    pub fn advance<R: Renderer, S: Scene<R> + ?Sized>(&mut self, scene: &mut S) -> bool {
+        self.apply_due_events(scene);
+
+        if self.time >= self.recording.duration || self.recording.step.is_zero() {
+            self.time = self.time.max(self.recording.duration);
+            self.apply_due_events(scene);
+
+            return false;
+        }
+
+        let elapsed = self.clock.tick().min(self.recording.duration - self.time);
+        scene.advance_and_apply(elapsed);
+        self.time += elapsed;
+
+        !self.is_finished()
+    }
+
+    /// Replays the whole recording into `scene`.
+    pub fn replay<R: Renderer, S: Scene<R> + ?Sized>(&mut self, scene: &mut S) {
+        while self.advance(scene) {}
+
+        self.apply_due_events(scene);
+    }
+}
@@ -1,12 +1,14 @@
 use core::{any::Any, time::Duration};
 
-use alloc::boxed::Box;
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
 
 use crate::{
     artboard::Artboard,
+    ffi,
     instantiate::{Handle, Instantiate},
     linear_animation::{LinearAnimation, Loop},
-    renderer::Renderer,
+    path::{FillRule, Rect},
+    renderer::{Color, Paint, PaintStyle, Path, Renderer},
     state_machine::StateMachine,
 };
 
@@ -14,11 +16,109 @@ pub(crate) fn transform(x: f32, y: f32, t: &[f32; 6]) -> [f32; 2] {
     [t[0] * x + t[2] * y + t[4], t[1] * x + t[3] * y + t[5]]
 }
 
+fn add_rect<P: Path>(path: &mut P, [x0, y0, x1, y1]: [f32; 4]) {
+    path.move_to(x0, y0);
+    path.line_to(x1, y0);
+    path.line_to(x1, y1);
+    path.line_to(x0, y1);
+    path.close();
+}
+
+/// Rounds `size * scale` up without `f32::ceil`, which isn't available without `std`.
+fn scaled_size(size: u32, scale: f32) -> u32 {
+    let scaled = size as f32 * scale;
+    let truncated = scaled as u32;
+
+    if (truncated as f32) < scaled {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+fn intersects(a: [f32; 4], b: [f32; 4]) -> bool {
+    a[0] < b[2] && b[0] < a[2] && a[1] < b[3] && b[1] < a[3]
+}
+
+/// Returns the bounds of `raw_artboard` drawn with `view_transform`.
+fn artboard_rect(raw_artboard: *mut ffi::Artboard, view_transform: &[f32; 6]) -> [f32; 4] {
+    // The view transform maps the artboard's frame, which starts at `(0, 0)` whatever its origin.
+    let (width, height) = unsafe {
+        (
+            ffi::rive_rs_artboard_width(raw_artboard),
+            ffi::rive_rs_artboard_height(raw_artboard),
+        )
+    };
+
+    let [x0, y0] = transform(0.0, 0.0, view_transform);
+    let [x1, y1] = transform(width, height, view_transform);
+
+    [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)]
+}
+
+/// How long a [`Scene`] plays for, as returned by [`Scene::duration_kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DurationKind {
+    /// The scene plays for the given duration and then stops.
+    Finite(Duration),
+    /// The scene loops and keeps playing until it is stopped.
+    Indefinite,
+    /// The duration of the scene cannot be known ahead of time, e.g. for state machines, where
+    /// it depends on inputs.
+    Unknown,
+}
+
+/// The cursor hosts should show over a [`Scene`], as returned by [`Scene::cursor`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Cursor {
+    #[default]
+    Default,
+    /// The pointer is over a shape that a listener reacts to, usually shown as a hand.
+    Pointer,
+}
+
+/// The type of a [`Scene`], as returned by [`Scene::kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SceneKind {
+    /// An [`Artboard`] drawn as is, without playing anything.
+    Artboard,
+    /// A [`LinearAnimation`], which can be scrubbed.
+    LinearAnimation,
+    /// A [`StateMachine`], which can be driven with inputs.
+    StateMachine,
+}
+
+/// What is drawn in the parts of a [`Viewport`] that the artboard doesn't cover.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Letterbox {
+    /// The margins are left untouched.
+    #[default]
+    Transparent,
+    /// The margins are filled with the color.
+    Color(Color),
+    /// The margins are filled with the background color of the artboard, or left untouched if
+    /// its background is not a solid color.
+    ArtboardBackground,
+}
+
+/// The area a [`Scene`] is fitted into when drawn.
+///
+/// A viewport remembers the transform of the last scene drawn into it in order to map pointer
+/// positions back into the scene, so a scene drawn into several viewports per frame, e.g. with
+/// [`Scene::draw_in`], needs a separate viewport for each of them.
 #[derive(Clone, Debug)]
 pub struct Viewport {
     pub(crate) width: u32,
     pub(crate) height: u32,
     pub(crate) inverse_view_transform: [f32; 6],
+    pub(crate) letterbox: Letterbox,
+    pub(crate) clip: bool,
+    pub(crate) visible_rect: Option<Rect>,
+    pub(crate) offscreen_advance_interval: Option<Duration>,
+    pub(crate) offscreen_elapsed: Duration,
+    pub(crate) is_culled: bool,
+    pub(crate) resolution_scale: f32,
+    pub(crate) needs_redraw: bool,
 }
 
 impl Viewport {
@@ -36,6 +136,254 @@ impl Viewport {
     pub fn resize(&mut self, width: u32, height: u32) {
         self.width = width;
         self.height = height;
+        self.needs_redraw = true;
+    }
+
+    #[inline]
+    pub fn resolution_scale(&self) -> f32 {
+        self.resolution_scale
+    }
+
+    /// Sets the fraction of the viewport's resolution that scenes are drawn at, e.g. `0.5` to
+    /// draw at half the width and height. Defaults to `1.0`.
+    ///
+    /// Scenes are then drawn into the top-left [`Viewport::render_size`] pixels, which the host
+    /// renders into a smaller target and scales up to the viewport's size. Curves are flattened
+    /// relative to the reduced resolution as well, so lower scales trade sharpness for speed on
+    /// low-end devices. Pointer positions keep using the viewport's own coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is not positive and finite.
+    #[inline]
+    pub fn set_resolution_scale(&mut self, scale: f32) {
+        assert!(
+            scale.is_finite() && scale > 0.0,
+            "resolution scale must be positive and finite"
+        );
+
+        self.resolution_scale = scale;
+        self.needs_redraw = true;
+    }
+
+    /// Returns the size of the target scenes drawn into the viewport should be rendered at,
+    /// which is the viewport's size multiplied by [`Viewport::resolution_scale`].
+    #[inline]
+    pub fn render_size(&self) -> (u32, u32) {
+        (
+            scaled_size(self.width, self.resolution_scale),
+            scaled_size(self.height, self.resolution_scale),
+        )
+    }
+
+    #[inline]
+    pub fn letterbox(&self) -> Letterbox {
+        self.letterbox
+    }
+
+    /// Sets what [`Scene::draw_in`] and [`Scene::advance_and_maybe_draw`] draw around the
+    /// artboard when it doesn't fill the viewport.
+    #[inline]
+    pub fn set_letterbox(&mut self, letterbox: Letterbox) {
+        self.letterbox = letterbox;
+        self.needs_redraw = true;
+    }
+
+    /// Returns `true` if scenes drawn into the viewport are clipped to its bounds.
+    #[inline]
+    pub fn is_clipped(&self) -> bool {
+        self.clip
+    }
+
+    /// Clips what [`Scene::draw_in`] and [`Scene::advance_and_maybe_draw`] draw to the bounds of
+    /// the viewport, so that content animating outside of it doesn't bleed into its
+    /// surroundings. Off by default.
+    #[inline]
+    pub fn set_clip(&mut self, clip: bool) {
+        self.clip = clip;
+        self.needs_redraw = true;
+    }
+
+    #[inline]
+    pub fn visible_rect(&self) -> Option<Rect> {
+        self.visible_rect
+    }
+
+    /// Sets the part of the viewport, in its own coordinates, that ends up on the target surface,
+    /// e.g. the surface's rectangle moved by minus the viewport's position on it. `None`, the
+    /// default, treats everything as visible.
+    ///
+    /// [`Scene::draw_in`] and [`Scene::advance_and_maybe_draw`] skip drawing when nothing they
+    /// would draw overlaps the visible rectangle, e.g. for the offscreen rows of a scrolling list.
+    /// Content of artboards that don't clip to their bounds is culled as if they did.
+    #[inline]
+    pub fn set_visible_rect(&mut self, visible_rect: Option<Rect>) {
+        self.visible_rect = visible_rect;
+        self.needs_redraw = true;
+    }
+
+    /// Returns `true` if the last scene drawn into the viewport was skipped because it was
+    /// entirely outside of the [visible rectangle](Viewport::set_visible_rect).
+    #[inline]
+    pub fn is_culled(&self) -> bool {
+        self.is_culled
+    }
+
+    #[inline]
+    pub fn offscreen_advance_interval(&self) -> Option<Duration> {
+        self.offscreen_advance_interval
+    }
+
+    /// Throttles [`Scene::advance_and_maybe_draw`] while the viewport [is
+    /// culled](Viewport::is_culled): the elapsed time is accumulated and the scene is only
+    /// advanced once at least `interval` has passed, so it stays in sync at a fraction of the
+    /// cost. `None`, the default, keeps advancing every frame.
+    #[inline]
+    pub fn set_offscreen_advance_interval(&mut self, interval: Option<Duration>) {
+        self.offscreen_advance_interval = interval;
+    }
+
+    /// Makes the next [`Scene::advance_and_maybe_draw`] draw even if the scene didn't change,
+    /// e.g. after editing it outside of advancing or when the host lost the previous frame.
+    ///
+    /// Changing the viewport's size or any of its drawing settings does this automatically.
+    #[inline]
+    pub fn request_redraw(&mut self) {
+        self.needs_redraw = true;
+    }
+
+    /// Returns whether a scene whose advance returned `is_playing` has to be drawn again.
+    ///
+    /// The frame after a scene stops playing is still drawn since the advance that settles it
+    /// applies its final pose.
+    pub(crate) fn take_redraw(&mut self, is_playing: bool) -> bool {
+        let needs_redraw = is_playing || self.needs_redraw;
+        self.needs_redraw = is_playing;
+
+        needs_redraw
+    }
+
+    /// Returns the time to advance the scene by this frame, or `None` if advancing is throttled.
+    pub(crate) fn throttle(&mut self, elapsed: Duration) -> Option<Duration> {
+        self.offscreen_elapsed += elapsed;
+
+        match self.offscreen_advance_interval {
+            Some(interval) if self.is_culled && self.offscreen_elapsed < interval => None,
+            _ => Some(core::mem::take(&mut self.offscreen_elapsed)),
+        }
+    }
+
+    /// Returns `true` if nothing drawn for `raw_artboard` with `view_transform` would be
+    /// visible.
+    pub(crate) fn culls(
+        &self,
+        raw_artboard: *mut ffi::Artboard,
+        view_transform: &[f32; 6],
+    ) -> bool {
+        let Some(visible_rect) = self.visible_rect else {
+            return false;
+        };
+
+        let visible_rect = [
+            visible_rect.min.x,
+            visible_rect.min.y,
+            visible_rect.max.x,
+            visible_rect.max.y,
+        ];
+        let viewport_rect = [0.0, 0.0, self.width as f32, self.height as f32];
+
+        if self.clip && !intersects(viewport_rect, visible_rect) {
+            return true;
+        }
+
+        let is_letterbox_visible =
+            self.letterbox != Letterbox::Transparent && intersects(viewport_rect, visible_rect);
+        let is_artboard_visible =
+            intersects(artboard_rect(raw_artboard, view_transform), visible_rect);
+
+        !is_letterbox_visible && !is_artboard_visible
+    }
+
+    /// Clips everything drawn until the next [`Renderer::state_pop`] to the viewport if clipping
+    /// is enabled.
+    pub(crate) fn apply_clip<R: Renderer>(&self, renderer: &mut R) {
+        if !self.clip {
+            return;
+        }
+
+        let mut path = R::Path::default();
+        add_rect(&mut path, [0.0, 0.0, self.width as f32, self.height as f32]);
+
+        renderer.set_clip(&path);
+    }
+
+    /// Fills the parts of the viewport that `raw_artboard` drawn with `view_transform` doesn't
+    /// cover, according to the letterbox setting.
+    pub(crate) fn draw_letterbox<R: Renderer>(
+        &self,
+        renderer: &mut R,
+        raw_artboard: *mut ffi::Artboard,
+        view_transform: &[f32; 6],
+    ) {
+        let color = match self.letterbox {
+            Letterbox::Transparent => return,
+            Letterbox::Color(color) => color,
+            Letterbox::ArtboardBackground => {
+                let mut color = Color {
+                    b: 0,
+                    g: 0,
+                    r: 0,
+                    a: 0,
+                };
+
+                if !unsafe { ffi::rive_rs_artboard_background_color(raw_artboard, &mut color) } {
+                    return;
+                }
+
+                color
+            }
+        };
+
+        let [width, height] = [self.width as f32, self.height as f32];
+        // The artboard can overflow the viewport, e.g. with `Fit::Cover`, in which case only the
+        // part inside of it is cut out.
+        let [x0, y0, x1, y1] = artboard_rect(raw_artboard, view_transform);
+        let artboard_rect = [
+            x0.clamp(0.0, width),
+            y0.clamp(0.0, height),
+            x1.clamp(0.0, width),
+            y1.clamp(0.0, height),
+        ];
+
+        let mut path = R::Path::default();
+        path.set_fill_rule(FillRule::EvenOdd);
+
+        add_rect(&mut path, [0.0, 0.0, width, height]);
+        add_rect(&mut path, artboard_rect);
+
+        let mut paint = R::Paint::default();
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(color);
+
+        renderer.draw_path(&path, &paint);
+    }
+
+    /// Returns the transform fitting `raw_artboard` into the viewport together with its inverse.
+    pub(crate) fn view_transforms(&self, raw_artboard: *mut ffi::Artboard) -> ([f32; 6], [f32; 6]) {
+        let mut view_transform = [0.0; 6];
+        let mut inverse_view_transform = [0.0; 6];
+
+        unsafe {
+            ffi::rive_rs_artboard_instance_transforms(
+                raw_artboard,
+                self.width,
+                self.height,
+                view_transform.as_mut_ptr(),
+                inverse_view_transform.as_mut_ptr(),
+            );
+        }
+
+        (view_transform, inverse_view_transform)
     }
 }
 
@@ -46,6 +394,39 @@ impl Default for Viewport {
             width: 0,
             height: 0,
             inverse_view_transform: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            letterbox: Letterbox::default(),
+            clip: false,
+            visible_rect: None,
+            offscreen_advance_interval: None,
+            offscreen_elapsed: Duration::ZERO,
+            is_culled: false,
+            resolution_scale: 1.0,
+            needs_redraw: true,
+        }
+    }
+}
+
+/// Options applied to the whole scene by [`Scene::draw_with`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DrawOptions {
+    /// Opacity the scene is composited with, between `0.0` and `1.0`.
+    pub opacity: f32,
+    /// Color the scene is multiplied with, if any.
+    pub tint: Option<Color>,
+}
+
+impl DrawOptions {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for DrawOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            tint: None,
         }
     }
 }
@@ -54,30 +435,296 @@ pub trait Scene<R: Renderer>: Send + Sync {
     fn width(&self) -> f32;
     fn height(&self) -> f32;
     fn name(&self) -> &str;
+    /// Returns the type of the scene, so that code holding a `dyn Scene` can tell which
+    /// capabilities it has without downcasting it.
+    ///
+    /// By default, the kind is found by downcasting the scene, and scenes that are neither a
+    /// [`LinearAnimation`] nor a [`StateMachine`] are reported as
+    /// [`SceneKind::Artboard`].
+    #[inline]
+    fn kind(&self) -> SceneKind {
+        if self.as_state_machine().is_some() {
+            SceneKind::StateMachine
+        } else if self.as_linear_animation().is_some() {
+            SceneKind::LinearAnimation
+        } else {
+            SceneKind::Artboard
+        }
+    }
+    /// Returns the loop mode of the scene.
+    ///
+    /// Linear animations report their current loop mode, including overrides set with
+    /// [`LinearAnimation::set_loop`]. Artboards don't play animations on their own and report the
+    /// loop mode of the linear animation that [`Handle::Default`] instantiates, or
+    /// [`Loop::OneShot`] if there is none.
+    ///
+    /// State machines don't have a single timeline and always report [`Loop::OneShot`]; they
+    /// keep playing for as long as any of their states has an animation in progress, and
+    /// [`Scene::advance_and_apply`] returns `false` once they settle.
     fn r#loop(&self) -> Loop;
     fn is_translucent(&self) -> bool;
+    /// Returns how long one pass through the scene's timeline takes, limited to the work area
+    /// like [`Scene::frame_count`].
     fn duration(&self) -> Option<Duration>;
-    fn pointer_down(&mut self, x: f32, y: f32, viewport: &Viewport);
-    fn pointer_move(&mut self, x: f32, y: f32, viewport: &Viewport);
-    fn pointer_up(&mut self, x: f32, y: f32, viewport: &Viewport);
+
+    /// Returns how long the scene plays for, so that players can decide whether to show
+    /// progress.
+    #[inline]
+    fn duration_kind(&self) -> DurationKind {
+        match (self.duration(), self.r#loop()) {
+            (Some(duration), Loop::OneShot) => DurationKind::Finite(duration),
+            (Some(_), Loop::Loop | Loop::PingPong) => DurationKind::Indefinite,
+            (None, _) => DurationKind::Unknown,
+        }
+    }
+
+    /// Returns the frame rate the scene was authored at, or `None` for scenes without a timeline,
+    /// like state machines.
+    #[inline]
+    fn fps(&self) -> Option<u32> {
+        None
+    }
+
+    /// Returns the number of authored frames in the scene, limited to the work area if the scene
+    /// has one enabled, or `None` for scenes without a timeline, like state machines.
+    #[inline]
+    fn frame_count(&self) -> Option<u32> {
+        None
+    }
+
+    /// Sends a pointer down at `(x, y)` in `viewport`'s coordinates.
+    ///
+    /// The position is mapped to artboard space with the view transform of the last time the
+    /// scene was drawn into `viewport`.
+    #[inline]
+    fn pointer_down(&mut self, x: f32, y: f32, viewport: &Viewport) {
+        let [x, y] = transform(x, y, &viewport.inverse_view_transform);
+        self.pointer_down_artboard(x, y);
+    }
+
+    /// Sends a pointer move at `(x, y)` in `viewport`'s coordinates.
+    ///
+    /// See [`Scene::pointer_down`].
+    #[inline]
+    fn pointer_move(&mut self, x: f32, y: f32, viewport: &Viewport) {
+        let [x, y] = transform(x, y, &viewport.inverse_view_transform);
+        self.pointer_move_artboard(x, y);
+    }
+
+    /// Sends a pointer up at `(x, y)` in `viewport`'s coordinates.
+    ///
+    /// See [`Scene::pointer_down`].
+    #[inline]
+    fn pointer_up(&mut self, x: f32, y: f32, viewport: &Viewport) {
+        let [x, y] = transform(x, y, &viewport.inverse_view_transform);
+        self.pointer_up_artboard(x, y);
+    }
+
+    /// Returns the cursor to show at `(x, y)` in `viewport`'s coordinates: [`Cursor::Pointer`]
+    /// over shapes that the listeners of a state machine react to, as hit-tested by
+    /// [`StateMachine::hit_test`], and [`Cursor::Default`] everywhere else.
+    #[inline]
+    fn cursor(&self, x: f32, y: f32, viewport: &Viewport) -> Cursor {
+        match self.as_state_machine() {
+            Some(state_machine) if state_machine.hit_test(x, y, viewport) => Cursor::Pointer,
+            _ => Cursor::Default,
+        }
+    }
+
+    /// Sends a pointer down at `(x, y)` in artboard space, for hosts that map pointer positions
+    /// themselves instead of going through a [`Viewport`].
+    fn pointer_down_artboard(&mut self, x: f32, y: f32);
+    /// Sends a pointer move at `(x, y)` in artboard space.
+    ///
+    /// See [`Scene::pointer_down_artboard`].
+    fn pointer_move_artboard(&mut self, x: f32, y: f32);
+    /// Sends a pointer up at `(x, y)` in artboard space.
+    ///
+    /// See [`Scene::pointer_down_artboard`].
+    fn pointer_up_artboard(&mut self, x: f32, y: f32);
     fn advance_and_apply(&mut self, elapsed: Duration) -> bool;
     fn draw(&self, renderer: &mut R);
+
+    /// Draws the scene composited into a single layer with `options` applied.
+    ///
+    /// The layer covers the scene's `width` by `height` bounds, so anything drawn outside of them
+    /// is clipped. Renderers that don't support layers ignore `options`; see
+    /// [`Renderer::push_layer`].
+    #[inline]
+    fn draw_with(&self, renderer: &mut R, options: &DrawOptions) {
+        if options.is_default() {
+            return self.draw(renderer);
+        }
+
+        renderer.push_layer(self.width(), self.height(), options.opacity, options.tint);
+        self.draw(renderer);
+        renderer.pop_layer();
+    }
+
+    /// Draws the scene fitted into `viewport` without advancing it.
+    ///
+    /// This makes it possible to draw the same scene into several viewports in one frame, e.g. a
+    /// minimap and a main view, after a single [`Scene::advance_and_apply`]. Pointer events
+    /// should then be passed together with the viewport they happened in.
+    ///
+    /// The margins around the artboard are filled according to [`Viewport::letterbox`], and
+    /// drawing is clipped to the viewport if [`Viewport::is_clipped`]. Nothing is drawn if the
+    /// result would be outside of [`Viewport::visible_rect`].
+    fn draw_in(&self, renderer: &mut R, viewport: &mut Viewport) {
+        let artboard = self.artboard();
+        let raw_artboard = unsafe { artboard.as_raw() };
+        let (view_transform, inverse_view_transform) = viewport.view_transforms(raw_artboard);
+
+        viewport.inverse_view_transform = inverse_view_transform;
+        viewport.is_culled = viewport.culls(raw_artboard, &view_transform);
+
+        if viewport.is_culled {
+            return;
+        }
+
+        renderer.state_push();
+
+        let scale = viewport.resolution_scale;
+        if scale != 1.0 {
+            renderer.transform(&[scale, 0.0, 0.0, scale, 0.0, 0.0]);
+        }
+
+        viewport.apply_clip(renderer);
+        viewport.draw_letterbox(renderer, raw_artboard, &view_transform);
+        renderer.transform(&view_transform);
+
+        self.draw(renderer);
+
+        renderer.state_pop();
+    }
+
+    /// Advances the scene by `elapsed` and draws it fitted into `viewport` if it changed.
+    ///
+    /// Drawing is skipped when the advance reports that the scene settled and it was already
+    /// drawn in its final state, when advancing is
+    /// [throttled](Viewport::set_offscreen_advance_interval), and when the scene is
+    /// [culled](Viewport::is_culled). Changes to the viewport and [`Viewport::request_redraw`]
+    /// force a draw.
+    ///
+    /// Returns `true` if the scene was drawn. When it returns `false`, `renderer` received
+    /// nothing, so hosts that rebuild their frame from scratch should keep presenting the
+    /// previous one instead.
     fn advance_and_maybe_draw(
         &mut self,
         renderer: &mut R,
         elapsed: Duration,
         viewport: &mut Viewport,
-    ) -> bool;
+    ) -> bool {
+        let is_playing = match viewport.throttle(elapsed) {
+            Some(elapsed) => self.advance_and_apply(elapsed),
+            None => false,
+        };
+
+        if !viewport.take_redraw(is_playing) {
+            return false;
+        }
+
+        self.draw_in(renderer, viewport);
+
+        !viewport.is_culled
+    }
+
     fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Returns a new handle to the [`Artboard`] this scene is drawing.
+    ///
+    /// The returned handle shares the same instance, so changes made through it (e.g. to
+    /// [`components`](crate::components)) are visible to the scene.
+    fn artboard(&self) -> Artboard<R>;
+
+    /// Instantiates the scene of the same kind and name from `artboard`, e.g. of a
+    /// [reloaded](crate::File::reload) version of the file, and carries the scene's state over,
+    /// so that hot reloading doesn't lose the scenario being tested.
+    ///
+    /// The values of bool and number inputs with matching names and the texts of text runs with
+    /// matching names are copied, as are the time, direction, loop count and the loop mode and
+    /// work area overrides of linear animations. Listeners with matching names that were
+    /// disabled with [`StateMachine::set_listener_enabled`] stay disabled.
+    ///
+    /// States are restored on a best-effort basis: the new scene is advanced once without elapsed
+    /// time, which re-enters the states the copied inputs lead to, but not the ones reached
+    /// through triggers or timing. Wrappers like [`Hooked`](crate::Hooked) are not carried over.
+    ///
+    /// Returns `None` if `artboard` has no scene with the same name.
+    #[inline]
+    fn migrate_to(&self, artboard: &Artboard<R>) -> Option<Box<dyn Scene<R>>> {
+        crate::migrate::migrate(self, artboard)
+    }
+
+    /// Returns the scene as an [`Artboard`] if it is one.
+    #[inline]
+    fn as_artboard(&self) -> Option<&Artboard<R>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the scene as a mutable [`Artboard`] if it is one.
+    #[inline]
+    fn as_artboard_mut(&mut self) -> Option<&mut Artboard<R>> {
+        self.as_any_mut().downcast_mut()
+    }
+
+    /// Returns the scene as a [`LinearAnimation`] if it is one.
+    #[inline]
+    fn as_linear_animation(&self) -> Option<&LinearAnimation<R>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the scene as a mutable [`LinearAnimation`] if it is one.
+    #[inline]
+    fn as_linear_animation_mut(&mut self) -> Option<&mut LinearAnimation<R>> {
+        self.as_any_mut().downcast_mut()
+    }
+
+    /// Returns the scene as a [`StateMachine`] if it is one.
+    #[inline]
+    fn as_state_machine(&self) -> Option<&StateMachine<R>> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the scene as a mutable [`StateMachine`] if it is one.
+    #[inline]
+    fn as_state_machine_mut(&mut self) -> Option<&mut StateMachine<R>> {
+        self.as_any_mut().downcast_mut()
+    }
 }
 
 macro_rules! impl_scene {
-    ( $type:tt ) => {
+    (
+        $type:tt
+        $(, before_advance: $before_advance:ident)?
+        $(, after_advance: $after_advance:ident)?
+        $(, advance_and_apply: $advance_and_apply:ident)?
+        $(, duration: $duration:ident)?
+        $(, dispatch_pointer: $dispatch_pointer:ident)?
+        $( { $( $item:item )* } )?
+    ) => {
         impl<R: Renderer> crate::scene::Scene<R> for $type<R> {
+            $( $( $item )* )?
+
             fn as_any(&self) -> &dyn ::core::any::Any {
                 self
             }
 
+            fn as_any_mut(&mut self) -> &mut dyn ::core::any::Any {
+                self
+            }
+
+            #[inline]
+            fn artboard(&self) -> crate::artboard::Artboard<R> {
+                crate::artboard::Artboard::from_inner(self.artboard.clone())
+            }
+
+            #[inline]
+            fn kind(&self) -> crate::scene::SceneKind {
+                crate::scene::SceneKind::$type
+            }
+
             #[inline]
             fn width(&self) -> f32 {
                 unsafe { crate::ffi::rive_rs_scene_width(self.raw_scene()) }
@@ -117,31 +764,42 @@ macro_rules! impl_scene {
 
             #[inline]
             fn duration(&self) -> Option<::core::time::Duration> {
-                ::core::time::Duration::try_from_secs_f32(unsafe {
-                    crate::ffi::rive_rs_scene_duration(self.raw_scene())
-                })
-                .ok()
+                $(return self.$duration();)?
+
+                #[allow(unreachable_code)]
+                let seconds = unsafe { crate::ffi::rive_rs_scene_duration(self.raw_scene()) };
+
+                ::core::time::Duration::try_from_secs_f32(seconds).ok()
             }
 
             #[inline]
-            fn pointer_down(&mut self, x: f32, y: f32, viewport: &crate::scene::Viewport) {
-                let [x, y] = crate::scene::transform(x, y, &viewport.inverse_view_transform);
+            fn pointer_down_artboard(&mut self, x: f32, y: f32) {
+                // The hook replaces rive-cpp's dispatch entirely.
+                $(return self.$dispatch_pointer(crate::ffi::PointerEvent::Down, x, y);)?
+
+                #[allow(unreachable_code)]
                 unsafe {
                     crate::ffi::rive_rs_scene_pointer_down(self.raw_scene(), x, y);
                 }
             }
 
             #[inline]
-            fn pointer_move(&mut self, x: f32, y: f32, viewport: &crate::scene::Viewport) {
-                let [x, y] = crate::scene::transform(x, y, &viewport.inverse_view_transform);
+            fn pointer_move_artboard(&mut self, x: f32, y: f32) {
+                // The hook replaces rive-cpp's dispatch entirely.
+                $(return self.$dispatch_pointer(crate::ffi::PointerEvent::Move, x, y);)?
+
+                #[allow(unreachable_code)]
                 unsafe {
                     crate::ffi::rive_rs_scene_pointer_move(self.raw_scene(), x, y);
                 }
             }
 
             #[inline]
-            fn pointer_up(&mut self, x: f32, y: f32, viewport: &crate::scene::Viewport) {
-                let [x, y] = crate::scene::transform(x, y, &viewport.inverse_view_transform);
+            fn pointer_up_artboard(&mut self, x: f32, y: f32) {
+                // The hook replaces rive-cpp's dispatch entirely.
+                $(return self.$dispatch_pointer(crate::ffi::PointerEvent::Up, x, y);)?
+
+                #[allow(unreachable_code)]
                 unsafe {
                     crate::ffi::rive_rs_scene_pointer_up(self.raw_scene(), x, y);
                 }
@@ -149,16 +807,29 @@ macro_rules! impl_scene {
 
             #[inline]
             fn advance_and_apply(&mut self, elapsed: ::core::time::Duration) -> bool {
-                unsafe {
-                    crate::ffi::rive_rs_scene_advance_and_apply(
-                        self.raw_scene(),
-                        elapsed.as_secs_f32(),
-                    )
-                }
+                crate::trace::span!(concat!(stringify!($type), "::advance_and_apply"));
+
+                $(self.$before_advance(elapsed);)?
+
+                // The hook returns `None` to leave advancing to rive-cpp.
+                let is_playing = None
+                    $(.or_else(|| self.$advance_and_apply(elapsed)))?
+                    .unwrap_or_else(|| unsafe {
+                        crate::ffi::rive_rs_scene_advance_and_apply(
+                            self.raw_scene(),
+                            elapsed.as_secs_f32(),
+                        )
+                    });
+
+                $(self.$after_advance();)?
+
+                is_playing
             }
 
             #[inline]
             fn draw(&self, renderer: &mut R) {
+                crate::trace::span!(concat!(stringify!($type), "::draw"));
+
                 unsafe {
                     crate::ffi::rive_rs_scene_draw(
                         self.raw_scene(),
@@ -168,48 +839,33 @@ macro_rules! impl_scene {
                     );
                 }
             }
-
-            #[inline]
-            fn advance_and_maybe_draw(
-                &mut self,
-                renderer: &mut R,
-                elapsed: ::core::time::Duration,
-                viewport: &mut crate::scene::Viewport,
-            ) -> bool {
-                let mut view_transform = [0.0; 6];
-                let mut inverse_view_transform = [0.0; 6];
-
-                unsafe {
-                    crate::ffi::rive_rs_artboard_instance_transforms(
-                        self.raw_artboard(),
-                        viewport.width,
-                        viewport.height,
-                        view_transform.as_mut_ptr(),
-                        inverse_view_transform.as_mut_ptr(),
-                    );
-                }
-
-                viewport.inverse_view_transform = inverse_view_transform;
-
-                if !self.advance_and_apply(elapsed) {
-                    // return false;
-                }
-
-                renderer.state_push();
-                renderer.transform(&view_transform);
-
-                self.draw(renderer);
-
-                renderer.state_pop();
-
-                true
-            }
         }
     };
 }
 
 pub(crate) use impl_scene;
 
+impl<R: Renderer> From<Artboard<R>> for Box<dyn Scene<R>> {
+    #[inline]
+    fn from(artboard: Artboard<R>) -> Self {
+        Box::new(artboard)
+    }
+}
+
+impl<R: Renderer> From<LinearAnimation<R>> for Box<dyn Scene<R>> {
+    #[inline]
+    fn from(linear_animation: LinearAnimation<R>) -> Self {
+        Box::new(linear_animation)
+    }
+}
+
+impl<R: Renderer> From<StateMachine<R>> for Box<dyn Scene<R>> {
+    #[inline]
+    fn from(state_machine: StateMachine<R>) -> Self {
+        Box::new(state_machine)
+    }
+}
+
 impl<R: Renderer> Instantiate for Box<dyn Scene<R>> {
     type From = Artboard<R>;
 
@@ -221,4 +877,27 @@ impl<R: Renderer> Instantiate for Box<dyn Scene<R>> {
                     .map(|la| Box::new(la) as Box<dyn Scene<R>>)
             })
     }
+
+    /// Returns the handles of all state machines, followed by the ones of all linear animations.
+    ///
+    /// Linear animations are referred to by name, since their indices resolve to state machines
+    /// first. A linear animation sharing its name with a state machine can't be instantiated
+    /// this way and is skipped.
+    fn handles(from: &Self::From) -> Vec<(Handle, String)> {
+        let mut handles = StateMachine::handles(from);
+        let state_machine_count = handles.len();
+
+        for index in 0..from.linear_animation_count() {
+            let name = from.linear_animation_name(index);
+
+            if !handles[..state_machine_count]
+                .iter()
+                .any(|(_, state_machine)| state_machine == name)
+            {
+                handles.push((Handle::Name(name.to_owned().into()), name.to_owned()));
+            }
+        }
+
+        handles
+    }
 }
@@ -0,0 +1,225 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, marker::PhantomData, time::Duration};
+
+use crate::{
+    renderer::Renderer,
+    scene::{Cursor, Scene, Viewport},
+};
+
+struct Layer<S: ?Sized> {
+    scene: Box<S>,
+    x: f32,
+    y: f32,
+    viewport: Viewport,
+}
+
+impl<S: ?Sized> Layer<S> {
+    fn local_pos(&self, x: f32, y: f32) -> Option<(f32, f32)> {
+        let (x, y) = (x - self.x, y - self.y);
+        let is_inside = (0.0..self.viewport.width() as f32).contains(&x)
+            && (0.0..self.viewport.height() as f32).contains(&y);
+
+        is_inside.then_some((x, y))
+    }
+}
+
+/// Scenes drawn on top of each other, each fitted into its own rectangle.
+///
+/// Layers are drawn in the order they were pushed, so the last one is on top. Pointer events
+/// are routed to the topmost state machine whose listeners are hit, see
+/// [`StateMachine::hit_test`](crate::StateMachine::hit_test). Once a layer receives a pointer
+/// down, it keeps receiving pointer events until the matching pointer up, even outside of its
+/// rectangle. When the pointer moves from one layer to another, the layer it left receives the
+/// move too, so that its exit listeners fire.
+pub struct SceneStack<R: Renderer, S: ?Sized = dyn Scene<R>> {
+    layers: Vec<Layer<S>>,
+    captured: Option<usize>,
+    hovered: Option<usize>,
+    _phantom: PhantomData<R>,
+}
+
+impl<R: Renderer, S: Scene<R> + ?Sized> SceneStack<R, S> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes `scene` on top of the stack, fitted into the `width` by `height` rectangle at
+    /// `(x, y)`, and returns its index.
+    pub fn push(&mut self, scene: Box<S>, x: f32, y: f32, width: u32, height: u32) -> usize {
+        let mut viewport = Viewport::default();
+        viewport.resize(width, height);
+
+        self.layers.push(Layer {
+            scene,
+            x,
+            y,
+            viewport,
+        });
+
+        self.layers.len() - 1
+    }
+
+    /// Removes the layer at `index`, moving the ones above it down by one.
+    ///
+    /// A pointer captured by another layer stays captured.
+    pub fn remove(&mut self, index: usize) -> Box<S> {
+        let shift = |layer: Option<usize>| match layer {
+            Some(layer) if layer == index => None,
+            Some(layer) if layer > index => Some(layer - 1),
+            layer => layer,
+        };
+
+        self.captured = shift(self.captured);
+        self.hovered = shift(self.hovered);
+
+        self.layers.remove(index).scene
+    }
+
+    /// Moves and resizes the rectangle the layer at `index` is fitted into.
+    pub fn set_bounds(&mut self, index: usize, x: f32, y: f32, width: u32, height: u32) {
+        let layer = &mut self.layers[index];
+
+        layer.x = x;
+        layer.y = y;
+        layer.viewport.resize(width, height);
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&S> {
+        self.layers.get(index).map(|layer| &*layer.scene)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut S> {
+        self.layers.get_mut(index).map(|layer| &mut *layer.scene)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Advances every layer by `elapsed` and draws them from bottom to top.
+    pub fn advance_and_draw(&mut self, renderer: &mut R, elapsed: Duration) {
+        for layer in &mut self.layers {
+            layer.scene.advance_and_apply(elapsed);
+
+            renderer.state_push();
+            renderer.transform(&[1.0, 0.0, 0.0, 1.0, layer.x, layer.y]);
+
+            layer.scene.draw_in(renderer, &mut layer.viewport);
+
+            renderer.state_pop();
+        }
+    }
+
+    fn target(&self, x: f32, y: f32) -> Option<usize> {
+        if let Some(index) = self.captured {
+            return Some(index);
+        }
+
+        self.layers.iter().rposition(|layer| {
+            let Some((x, y)) = layer.local_pos(x, y) else {
+                return false;
+            };
+
+            layer
+                .scene
+                .as_state_machine()
+                .is_some_and(|state_machine| state_machine.hit_test(x, y, &layer.viewport))
+        })
+    }
+
+    /// Returns the cursor to show at `(x, y)`, as reported by the layer that captured the
+    /// pointer, or else by the topmost layer it hits.
+    pub fn cursor(&self, x: f32, y: f32) -> Cursor {
+        self.target(x, y).map_or(Cursor::Default, |index| {
+            let layer = &self.layers[index];
+            layer
+                .scene
+                .cursor(x - layer.x, y - layer.y, &layer.viewport)
+        })
+    }
+
+    /// Sends a pointer down at `(x, y)` to the topmost layer it hits and returns its index.
+    pub fn pointer_down(&mut self, x: f32, y: f32) -> Option<usize> {
+        let index = self.target(x, y)?;
+        let layer = &mut self.layers[index];
+
+        layer
+            .scene
+            .pointer_down(x - layer.x, y - layer.y, &layer.viewport);
+        self.captured = Some(index);
+
+        Some(index)
+    }
+
+    /// Sends a pointer move at `(x, y)` to the layer that captured the pointer, or else to the
+    /// topmost layer it hits, and returns its index.
+    ///
+    /// If the pointer left the layer that received the previous move, that layer receives this
+    /// move as well.
+    pub fn pointer_move(&mut self, x: f32, y: f32) -> Option<usize> {
+        let target = self.target(x, y);
+
+        if let Some(hovered) = self.hovered.filter(|&hovered| Some(hovered) != target) {
+            let layer = &mut self.layers[hovered];
+
+            layer
+                .scene
+                .pointer_move(x - layer.x, y - layer.y, &layer.viewport);
+        }
+
+        self.hovered = target;
+
+        let index = target?;
+        let layer = &mut self.layers[index];
+
+        layer
+            .scene
+            .pointer_move(x - layer.x, y - layer.y, &layer.viewport);
+
+        Some(index)
+    }
+
+    /// Sends a pointer up at `(x, y)` to the layer that captured the pointer, or else to the
+    /// topmost layer it hits, releases the capture, and returns the layer's index.
+    pub fn pointer_up(&mut self, x: f32, y: f32) -> Option<usize> {
+        let index = self.target(x, y)?;
+        let layer = &mut self.layers[index];
+
+        layer
+            .scene
+            .pointer_up(x - layer.x, y - layer.y, &layer.viewport);
+        self.captured = None;
+
+        Some(index)
+    }
+}
+
+impl<R: Renderer, S: ?Sized> Default for SceneStack<R, S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            layers: Vec::new(),
+            captured: None,
+            hovered: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<R: Renderer, S: ?Sized> fmt::Debug for SceneStack<R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SceneStack")
+            .field("len", &self.layers.len())
+            .field("captured", &self.captured)
+            .finish()
+    }
+}
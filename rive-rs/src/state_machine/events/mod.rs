@@ -6,17 +6,74 @@ use crate::{
     raw_iter::{impl_iter, Raw},
 };
 
-mod properties;
+pub use crate::ffi::Property;
 
-pub use properties::Property;
+/// Where an [`EventKind::OpenUrl`] event asks for its URL to be opened, mirroring the HTML
+/// `target` attribute.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UrlTarget {
+    /// `_blank`: a new window or tab.
+    Blank,
+    /// `_parent`: the parent frame of the current one.
+    Parent,
+    /// `_self`: the current frame.
+    Same,
+    /// `_top`: the topmost frame.
+    Top,
+}
+
+impl UrlTarget {
+    fn from_raw(target: u32) -> Self {
+        match target {
+            1 => UrlTarget::Parent,
+            2 => UrlTarget::Same,
+            3 => UrlTarget::Top,
+            _ => UrlTarget::Blank,
+        }
+    }
+}
+
+/// The type of a reported [`Event`] together with its type-specific data.
+///
+/// Audio events will be reported once the runtime supports audio.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum EventKind {
+    /// An event without any built-in behavior.
+    General,
+    /// An event asking the host to open `url`.
+    OpenUrl { url: String, target: UrlTarget },
+}
 
 #[derive(Clone, Debug)]
 pub struct Event {
     pub name: String,
+    pub kind: EventKind,
     pub delay: Duration,
+    /// Custom properties of the event, keyed by name.
     pub properties: BTreeMap<String, Property>,
 }
 
+impl Event {
+    /// Returns the bool property named `key`, if the event has one.
+    #[inline]
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        self.properties.get(key).and_then(Property::as_bool)
+    }
+
+    /// Returns the number property named `key`, if the event has one.
+    #[inline]
+    pub fn number(&self, key: &str) -> Option<f32> {
+        self.properties.get(key).and_then(Property::as_number)
+    }
+
+    /// Returns the string property named `key`, if the event has one.
+    #[inline]
+    pub fn string(&self, key: &str) -> Option<&str> {
+        self.properties.get(key).and_then(Property::as_str)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct RawStateMachine(pub *mut ffi::StateMachine);
 
@@ -30,10 +87,12 @@ impl Raw for RawStateMachine {
     unsafe fn get<'s>(self, index: usize) -> Self::Item<'s> {
         let mut raw_event = ptr::null_mut();
         let mut name = String::new();
+        let mut url = String::new();
+        let mut target = 0;
         let mut delay = 0.0;
         let mut properties = BTreeMap::new();
 
-        unsafe {
+        let is_open_url = unsafe {
             ffi::rive_rs_state_machine_get_event(
                 self.0,
                 index,
@@ -45,10 +104,22 @@ impl Raw for RawStateMachine {
                 raw_event,
                 &mut properties as *mut BTreeMap<String, Property>,
             );
-        }
+
+            ffi::rive_rs_event_open_url(raw_event, &mut url as *mut String, &mut target as *mut u32)
+        };
+
+        let kind = if is_open_url {
+            EventKind::OpenUrl {
+                url,
+                target: UrlTarget::from_raw(target),
+            }
+        } else {
+            EventKind::General
+        };
 
         Event {
             name,
+            kind,
             delay: Duration::from_secs_f32(delay),
             properties,
         }
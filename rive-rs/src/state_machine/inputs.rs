@@ -1,3 +1,4 @@
+use alloc::string::String;
 use core::{fmt, marker::PhantomData, ptr, slice, str};
 
 use crate::{
@@ -38,6 +39,8 @@ impl Bool<'_> {
         unsafe { input_name(self.raw_bool as *mut ffi::Input) }
     }
 
+    /// Returns the current value of the input, including changes made by the state machine
+    /// itself, e.g. by listeners, during the last advance.
     pub fn get(&self) -> bool {
         unsafe { ffi::rive_rs_bool_get(self.raw_bool) }
     }
@@ -72,6 +75,8 @@ impl Number<'_> {
         unsafe { input_name(self.raw_number as *mut ffi::Input) }
     }
 
+    /// Returns the current value of the input, including changes made by the state machine
+    /// itself, e.g. by listeners, during the last advance.
     pub fn get(&self) -> f32 {
         unsafe { ffi::rive_rs_number_get(self.raw_number) }
     }
@@ -123,6 +128,24 @@ impl<'s> fmt::Debug for Trigger<'s> {
     }
 }
 
+/// The value of a bool or number input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputValue {
+    Bool(bool),
+    Number(f32),
+}
+
+/// A change of an input's value, as returned by
+/// [`StateMachine::drain_input_changes`](crate::StateMachine::drain_input_changes).
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputChange {
+    /// The index of the input, as used by
+    /// [`StateMachine::input_at`](crate::StateMachine::input_at).
+    pub index: usize,
+    pub name: String,
+    pub value: InputValue,
+}
+
 #[derive(Debug)]
 pub enum Input<'s> {
     Bool(Bool<'s>),
@@ -130,6 +153,25 @@ pub enum Input<'s> {
     Trigger(Trigger<'s>),
 }
 
+impl Input<'_> {
+    pub fn name(&self) -> &str {
+        match self {
+            Input::Bool(input) => input.name(),
+            Input::Number(input) => input.name(),
+            Input::Trigger(input) => input.name(),
+        }
+    }
+
+    /// Returns the current value of bool and number inputs, or `None` for triggers.
+    pub fn value(&self) -> Option<InputValue> {
+        match self {
+            Input::Bool(input) => Some(InputValue::Bool(input.get())),
+            Input::Number(input) => Some(InputValue::Number(input.get())),
+            Input::Trigger(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct RawStateMachine(pub *mut ffi::StateMachine);
 
@@ -1,25 +1,40 @@
-use alloc::sync::Arc;
-use core::{fmt, marker::PhantomData, ptr::NonNull};
+use alloc::{
+    borrow::ToOwned,
+    string::String,
+    sync::Arc,
+    vec::{self, Vec},
+};
+use core::{fmt, marker::PhantomData, ptr, ptr::NonNull, slice, str, time::Duration};
 
 use crate::{
     artboard::{Artboard, ArtboardInner},
     ffi,
     instantiate::{Handle, Instantiate},
+    path::Point,
     renderer::Renderer,
-    scene::impl_scene,
+    scene::{self, impl_scene, Viewport},
+    trace::span,
+    tween::{Easing, Tween},
 };
 
 mod events;
 mod inputs;
+mod states;
 
 pub use self::{
-    events::{Event, EventIter, Property},
-    inputs::{Bool, InputIter, Number, Trigger},
+    events::{Event, EventIter, EventKind, Property, UrlTarget},
+    inputs::{Bool, Input, InputChange, InputIter, InputValue, Number, Trigger},
+    states::{StateChange, StateChangeIter},
 };
 
 pub struct StateMachine<R: Renderer> {
     artboard: Arc<ArtboardInner>,
     raw_state_machine: *mut ffi::StateMachine,
+    tweens: Vec<(*mut ffi::Number, Tween)>,
+    observed_inputs: Option<Vec<Option<InputValue>>>,
+    input_changes: Vec<InputChange>,
+    disabled_listeners: Vec<usize>,
+    pointer_targets: *mut ffi::PointerTargets,
     _phantom: PhantomData<R>,
 }
 
@@ -28,6 +43,8 @@ impl<R: Renderer> Instantiate for StateMachine<R> {
 
     #[inline]
     fn instantiate(artboard: &Self::From, handle: Handle) -> Option<Self> {
+        span!("StateMachine::instantiate");
+
         let mut raw_state_machine: Option<NonNull<ffi::StateMachine>> = None;
 
         match handle {
@@ -58,20 +75,64 @@ impl<R: Renderer> Instantiate for StateMachine<R> {
         raw_state_machine.map(|raw_state_machine| StateMachine {
             artboard: artboard.as_inner().clone(),
             raw_state_machine: raw_state_machine.as_ptr(),
+            tweens: Vec::new(),
+            observed_inputs: None,
+            input_changes: Vec::new(),
+            disabled_listeners: Vec::new(),
+            pointer_targets: ptr::null_mut(),
             _phantom: PhantomData,
         })
     }
-}
 
-impl<R: Renderer> StateMachine<R> {
-    fn raw_artboard(&self) -> *mut ffi::Artboard {
-        self.artboard.raw_artboard
+    fn handles(artboard: &Self::From) -> Vec<(Handle, String)> {
+        (0..artboard.state_machine_count())
+            .map(|index| {
+                let name = artboard.state_machine_name(index).to_owned();
+                (Handle::Index(index), name)
+            })
+            .collect()
     }
+}
 
+impl<R: Renderer> StateMachine<R> {
     pub fn artboard(&self) -> Artboard<R> {
         Artboard::from_inner(self.artboard.clone())
     }
 
+    /// Takes ownership of a `rive::StateMachineInstance` instantiated from `artboard`.
+    ///
+    /// # Safety
+    ///
+    /// `raw_state_machine` must be a valid, heap-allocated instance created from `artboard`'s
+    /// `rive::ArtboardInstance` which is not owned by anything else. It is deleted when the
+    /// returned value is dropped.
+    #[inline]
+    pub unsafe fn from_raw(
+        artboard: &Artboard<R>,
+        raw_state_machine: *mut ffi::StateMachine,
+    ) -> Self {
+        Self {
+            artboard: artboard.as_inner().clone(),
+            raw_state_machine,
+            tweens: Vec::new(),
+            observed_inputs: None,
+            input_changes: Vec::new(),
+            disabled_listeners: Vec::new(),
+            pointer_targets: ptr::null_mut(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the underlying `rive::StateMachineInstance`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid for as long as `self` is alive. It must not be released.
+    #[inline]
+    pub unsafe fn as_raw(&self) -> *mut ffi::StateMachine {
+        self.raw_state_machine
+    }
+
     fn raw_scene(&self) -> *mut ffi::Scene {
         self.raw_state_machine as *mut ffi::Scene
     }
@@ -81,11 +142,27 @@ impl<R: Renderer> StateMachine<R> {
         EventIter::new(events::RawStateMachine(self.raw_state_machine))
     }
 
+    /// Returns the states the layers transitioned to during the last advance, in the order they
+    /// were entered.
+    #[inline]
+    pub fn state_changes(&self) -> StateChangeIter {
+        StateChangeIter::new(states::RawStateMachine(self.raw_state_machine))
+    }
+
     #[inline]
     pub fn inputs(&self) -> InputIter {
         InputIter::new(inputs::RawStateMachine(self.raw_state_machine))
     }
 
+    /// Returns the input at `index`, in the same order as [`StateMachine::inputs`].
+    ///
+    /// Looking inputs up by index avoids the name comparisons of the `get_*` methods, so it is
+    /// better suited for driving many inputs every frame.
+    #[inline]
+    pub fn input_at(&self, index: usize) -> Option<Input> {
+        self.inputs().nth(index)
+    }
+
     #[inline]
     pub fn get_bool(&self, name: &str) -> Option<Bool> {
         unsafe {
@@ -121,6 +198,253 @@ impl<R: Renderer> StateMachine<R> {
             .map(|ptr| Trigger::new(ptr.as_ptr()))
         }
     }
+
+    /// Returns the current value of the bool input named `name`.
+    ///
+    /// The value reflects changes made by the state machine itself during the last advance, so it
+    /// can be used to keep application state in sync with the machine.
+    #[inline]
+    pub fn bool_value(&self, name: &str) -> Option<bool> {
+        self.get_bool(name).map(|input| input.get())
+    }
+
+    /// Returns the current value of the number input named `name`.
+    ///
+    /// See [`StateMachine::bool_value`].
+    #[inline]
+    pub fn number_value(&self, name: &str) -> Option<f32> {
+        self.get_number(name).map(|input| input.get())
+    }
+
+    #[inline]
+    pub fn listener_count(&self) -> usize {
+        unsafe { ffi::rive_rs_state_machine_listener_count(self.raw_state_machine) }
+    }
+
+    /// Returns the name of the listener at `index`, or `None` if `index` is out of bounds.
+    pub fn listener_name(&self, index: usize) -> Option<&str> {
+        if index >= self.listener_count() {
+            return None;
+        }
+
+        let mut data = ptr::null();
+        let mut len = 0;
+
+        unsafe {
+            ffi::rive_rs_state_machine_listener_name(
+                self.raw_state_machine,
+                index,
+                &mut data as *mut *const u8,
+                &mut len as *mut usize,
+            );
+            Some(
+                str::from_utf8(slice::from_raw_parts(data, len))
+                    .expect("listener name is invalid UTF-8"),
+            )
+        }
+    }
+
+    /// Returns whether the listeners named `name` react to pointer events, or `None` if there is
+    /// no such listener.
+    pub fn is_listener_enabled(&self, name: &str) -> Option<bool> {
+        let mut indices = self.listener_indices(name).peekable();
+        indices.peek()?;
+
+        Some(indices.any(|index| self.disabled_listeners.binary_search(&index).is_err()))
+    }
+
+    /// Enables or disables the listeners named `name`, returning `false` if there is no such
+    /// listener.
+    ///
+    /// Disabled listeners ignore pointer events and are left out of [`StateMachine::hit_test`],
+    /// e.g. to stop a button from reacting to clicks while a modal covers it. This only affects
+    /// this instance; the file is left untouched.
+    ///
+    pub fn set_listener_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        let indices: Vec<usize> = self.listener_indices(name).collect();
+
+        for &index in &indices {
+            match (self.disabled_listeners.binary_search(&index), enabled) {
+                (Ok(position), true) => {
+                    self.disabled_listeners.remove(position);
+                }
+                (Err(position), false) => self.disabled_listeners.insert(position, index),
+                _ => (),
+            }
+        }
+
+        !indices.is_empty()
+    }
+
+    /// Enables or disables all listeners at once, e.g. to make the whole state machine ignore
+    /// the pointer. See [`StateMachine::set_listener_enabled`].
+    pub fn set_listeners_enabled(&mut self, enabled: bool) {
+        self.disabled_listeners.clear();
+
+        if !enabled {
+            self.disabled_listeners.extend(0..self.listener_count());
+        }
+    }
+
+    fn listener_indices<'s>(&'s self, name: &'s str) -> impl Iterator<Item = usize> + 's {
+        (0..self.listener_count()).filter(move |&index| self.listener_name(index) == Some(name))
+    }
+
+    /// Returns whether the pointer position `(x, y)` in `viewport` falls on any of the shapes
+    /// the state machine's enabled listeners react to.
+    ///
+    /// Shapes are approximated by their world bounds. The view transform is the one of the last
+    /// time the state machine was drawn into `viewport`.
+    pub fn hit_test(&self, x: f32, y: f32, viewport: &Viewport) -> bool {
+        let [x, y] = scene::transform(x, y, &viewport.inverse_view_transform);
+
+        // Listener bounds are relative to the artboard's origin rather than to its frame.
+        let mut bounds = [0.0; 4];
+        unsafe {
+            ffi::rive_rs_artboard_bounds(self.artboard.raw_artboard, bounds.as_mut_ptr());
+        }
+        let [x, y] = [x + bounds[0], y + bounds[1]];
+
+        self.listener_bounds().chunks_exact(2).any(|bounds| {
+            (bounds[0].x..=bounds[1].x).contains(&x) && (bounds[0].y..=bounds[1].y).contains(&y)
+        })
+    }
+
+    /// Returns the world bounds of the shapes the enabled listeners react to, as pairs of
+    /// minimum and maximum points.
+    pub(crate) fn listener_bounds(&self) -> Vec<Point> {
+        let mut points: Vec<Point> = Vec::new();
+        unsafe {
+            ffi::rive_rs_state_machine_listener_bounds(
+                self.raw_state_machine,
+                self.artboard.raw_artboard,
+                self.disabled_listeners.as_ptr(),
+                self.disabled_listeners.len(),
+                &mut points as *mut Vec<Point>,
+            );
+        }
+
+        points
+    }
+
+    /// Fires the trigger input named `name`, returning `false` if there is no such trigger.
+    #[inline]
+    pub fn trigger(&mut self, name: &str) -> bool {
+        self.get_trigger(name)
+            .map(|mut trigger| trigger.fire())
+            .is_some()
+    }
+
+    /// Animates the number input named `name` from its current value to `to` over `duration`,
+    /// returning `false` if there is no such number.
+    ///
+    /// The input is updated every time the state machine advances, right before it is applied.
+    /// A new tween replaces any tween already running on the same input, and setting the input
+    /// directly doesn't stop a running tween.
+    pub fn tween(&mut self, name: &str, to: f32, duration: Duration, easing: Easing) -> bool {
+        let raw_number = unsafe {
+            ffi::rive_rs_state_machine_get_number(self.raw_state_machine, name.as_ptr(), name.len())
+        };
+
+        if raw_number.is_null() {
+            return false;
+        }
+
+        let from = unsafe { ffi::rive_rs_number_get(raw_number) };
+        let tween = Tween::new(from, to, duration, easing);
+
+        match self.tweens.iter_mut().find(|(raw, _)| *raw == raw_number) {
+            Some((_, old)) => *old = tween,
+            None => self.tweens.push((raw_number, tween)),
+        }
+
+        true
+    }
+
+    /// Stops all running tweens, leaving their inputs at their current values.
+    #[inline]
+    pub fn clear_tweens(&mut self) {
+        self.tweens.clear();
+    }
+
+    /// Starts or stops recording changes of the bool and number inputs' values.
+    ///
+    /// While observing, every advance compares the inputs to their values after the previous
+    /// advance and records the ones that differ, whether they were set from Rust, by listeners,
+    /// or by the state machine itself. Changes that are undone before the next advance are not
+    /// recorded.
+    pub fn observe_inputs(&mut self, observe: bool) {
+        self.observed_inputs = observe.then(|| self.inputs().map(|input| input.value()).collect());
+
+        if !observe {
+            self.input_changes.clear();
+        }
+    }
+
+    /// Returns the input changes recorded since the last call, in the order of the inputs. See
+    /// [`StateMachine::observe_inputs`].
+    #[inline]
+    pub fn drain_input_changes(&mut self) -> vec::Drain<'_, InputChange> {
+        self.input_changes.drain(..)
+    }
+
+    fn record_input_changes(&mut self) {
+        let Some(observed_inputs) = &mut self.observed_inputs else {
+            return;
+        };
+
+        let inputs = InputIter::new(inputs::RawStateMachine(self.raw_state_machine));
+        for (index, (input, observed)) in inputs.zip(observed_inputs.iter_mut()).enumerate() {
+            let value = input.value();
+
+            if value != *observed {
+                *observed = value;
+
+                if let Some(value) = value {
+                    self.input_changes.push(InputChange {
+                        index,
+                        name: input.name().to_owned(),
+                        value,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Dispatches pointer events to the listeners and nested artboards like rive-cpp does, except
+    /// for disabled listeners, which rive-cpp could only skip by changing the listeners shared by
+    /// every instance of the state machine. Hover state is kept by this instance.
+    fn dispatch_pointer(&mut self, event: ffi::PointerEvent, x: f32, y: f32) {
+        if self.pointer_targets.is_null() {
+            self.pointer_targets = unsafe {
+                ffi::rive_rs_pointer_targets_new(self.raw_state_machine, self.artboard.raw_artboard)
+            };
+        }
+
+        unsafe {
+            ffi::rive_rs_state_machine_pointer(
+                self.raw_state_machine,
+                self.artboard.raw_artboard,
+                self.pointer_targets,
+                event,
+                x,
+                y,
+                self.disabled_listeners.as_ptr(),
+                self.disabled_listeners.len(),
+            );
+        }
+    }
+
+    fn advance_tweens(&mut self, elapsed: Duration) {
+        self.tweens.retain_mut(|(raw_number, tween)| {
+            let value = tween.advance(elapsed);
+            unsafe {
+                ffi::rive_rs_number_set(*raw_number, value);
+            }
+
+            !tween.is_finished()
+        });
+    }
 }
 
 impl<R: Renderer> fmt::Debug for StateMachine<R> {
@@ -132,6 +456,7 @@ impl<R: Renderer> fmt::Debug for StateMachine<R> {
 impl<R: Renderer> Drop for StateMachine<R> {
     fn drop(&mut self) {
         unsafe {
+            ffi::rive_rs_pointer_targets_release(self.pointer_targets);
             ffi::rive_rs_scene_release(self.raw_scene());
         }
     }
@@ -140,4 +465,9 @@ impl<R: Renderer> Drop for StateMachine<R> {
 unsafe impl<R: Renderer> Send for StateMachine<R> {}
 unsafe impl<R: Renderer> Sync for StateMachine<R> {}
 
-impl_scene!(StateMachine);
+impl_scene!(
+    StateMachine,
+    before_advance: advance_tweens,
+    after_advance: record_input_changes,
+    dispatch_pointer: dispatch_pointer
+);
@@ -0,0 +1,45 @@
+use alloc::string::String;
+
+use crate::{
+    ffi,
+    raw_iter::{impl_iter, Raw},
+};
+
+/// A state a layer of the state machine transitioned to during the last advance.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StateChange {
+    /// Name of the layer the state belongs to.
+    pub layer: String,
+    /// Name of the animation an animation state plays, `"Entry"`, `"Exit"`, or `"Any"` for the
+    /// built-in states, or an empty string for blend states.
+    pub state: String,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RawStateMachine(pub *mut ffi::StateMachine);
+
+impl Raw for RawStateMachine {
+    type Item<'s> = StateChange;
+
+    fn len(self) -> usize {
+        unsafe { ffi::rive_rs_state_machine_state_changed_count(self.0) }
+    }
+
+    unsafe fn get<'s>(self, index: usize) -> Self::Item<'s> {
+        let mut layer = String::new();
+        let mut state = String::new();
+
+        unsafe {
+            ffi::rive_rs_state_machine_get_state_changed(
+                self.0,
+                index,
+                &mut layer as *mut String,
+                &mut state as *mut String,
+            );
+        }
+
+        StateChange { layer, state }
+    }
+}
+
+impl_iter!(StateChangeIter, StateChange, RawStateMachine);
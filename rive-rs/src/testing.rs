@@ -0,0 +1,393 @@
+//! Renderer-less harness for unit testing state machines against `.riv` fixtures.
+//!
+//! A [`StateMachineTest`] drives a state machine with a [`NullRenderer`] and a fixed notion of
+//! time, and keeps track of the state every layer is in and of the events reported since the
+//! last advance. Its methods chain and its assertions panic with a description of what was
+//! found instead, so tests read like the interaction they check.
+//!
+//! Only available with the `testing` feature, usually enabled in `[dev-dependencies]`.
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use rive_rs::testing::StateMachineTest;
+//! StateMachineTest::new(include_bytes!("../../assets/rating-animation.riv"))
+//!     .unwrap()
+//!     .set_number("rating", 3.0)
+//!     .advance(Duration::from_millis(100))
+//!     .expect_state("Layer 1", "3_stars");
+//! ```
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, vec::Vec};
+use core::{fmt, time::Duration};
+
+use crate::{
+    artboard::Artboard,
+    file::{self, File},
+    instantiate::{Handle, Instantiate},
+    recording::{InputEvent, Recording, Replayer},
+    renderer::NullRenderer,
+    scene::Scene,
+    state_machine::{Event, StateMachine},
+};
+
+/// Error returned when a [`StateMachineTest`] cannot be set up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The file could not be loaded.
+    File(file::Error),
+    /// The file has no such artboard.
+    NoArtboard(Handle),
+    /// The artboard has no such state machine.
+    NoStateMachine {
+        artboard: String,
+        state_machine: Handle,
+    },
+}
+
+impl From<file::Error> for Error {
+    #[inline]
+    fn from(error: file::Error) -> Self {
+        Self::File(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::File(error) => error.fmt(f),
+            Error::NoArtboard(artboard) => write!(f, "file has no artboard {artboard:?}"),
+            Error::NoStateMachine {
+                artboard,
+                state_machine,
+            } => write!(
+                f,
+                "artboard {artboard:?} has no state machine {state_machine:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::File(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A state machine instantiated from a `.riv` file together with the layer states and events
+/// observed while driving it.
+pub struct StateMachineTest {
+    state_machine: StateMachine<NullRenderer>,
+    states: BTreeMap<String, String>,
+    events: Vec<Event>,
+    time: Duration,
+}
+
+impl StateMachineTest {
+    /// Instantiates the default state machine of the default artboard of the file in `data`.
+    pub fn new(data: &[u8]) -> Result<Self, Error> {
+        Self::with_handles(data, Handle::Default, Handle::Default)
+    }
+
+    /// Instantiates the state machine `state_machine` of the artboard `artboard` of the file in
+    /// `data`.
+    pub fn with_handles(
+        data: &[u8],
+        artboard: Handle,
+        state_machine: Handle,
+    ) -> Result<Self, Error> {
+        let file = File::<NullRenderer>::new(data)?;
+
+        let artboard =
+            Artboard::instantiate(&file, artboard.clone()).ok_or(Error::NoArtboard(artboard))?;
+        let state_machine = StateMachine::instantiate(&artboard, state_machine.clone())
+            .ok_or_else(|| Error::NoStateMachine {
+                artboard: artboard.name().to_owned(),
+                state_machine,
+            })?;
+
+        Ok(Self {
+            state_machine,
+            states: BTreeMap::new(),
+            events: Vec::new(),
+            time: Duration::ZERO,
+        })
+    }
+
+    #[inline]
+    pub fn state_machine(&self) -> &StateMachine<NullRenderer> {
+        &self.state_machine
+    }
+
+    /// Returns the state machine. Changes made through it directly are picked up by the next
+    /// [`StateMachineTest::advance`].
+    #[inline]
+    pub fn state_machine_mut(&mut self) -> &mut StateMachine<NullRenderer> {
+        &mut self.state_machine
+    }
+
+    /// Returns the total time the state machine was advanced by.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the state `layer` is in, or `None` if it hasn't entered any state yet.
+    #[inline]
+    pub fn state(&self, layer: &str) -> Option<&str> {
+        self.states.get(layer).map(String::as_str)
+    }
+
+    /// Returns the events reported since the last advance.
+    #[inline]
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Advances the state machine by `elapsed` in a single step.
+    pub fn advance(&mut self, elapsed: Duration) -> &mut Self {
+        self.state_machine.advance_and_apply(elapsed);
+        self.time += elapsed;
+
+        for change in self.state_machine.state_changes() {
+            self.states.insert(change.layer, change.state);
+        }
+
+        self.events = self.state_machine.events().collect();
+
+        self
+    }
+
+    /// Advances the state machine by `duration` in steps of at most `step`, collecting the
+    /// events reported during all of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    #[track_caller]
+    pub fn advance_by_steps(&mut self, duration: Duration, step: Duration) -> &mut Self {
+        assert!(!step.is_zero(), "step must not be zero");
+
+        let mut events = Vec::new();
+        let mut remaining = duration;
+
+        while !remaining.is_zero() {
+            let elapsed = step.min(remaining);
+
+            self.advance(elapsed);
+            events.append(&mut self.events);
+            remaining -= elapsed;
+        }
+
+        self.events = events;
+
+        self
+    }
+
+    /// Applies `event` without advancing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `event` sets an input the state machine doesn't have.
+    #[track_caller]
+    pub fn apply(&mut self, event: &InputEvent) -> &mut Self {
+        assert!(
+            event.apply(&mut self.state_machine),
+            "state machine {:?} has no input for {event:?}",
+            self.state_machine.name()
+        );
+
+        self
+    }
+
+    /// Sets the bool input `name`.
+    #[track_caller]
+    pub fn set_bool(&mut self, name: &str, value: bool) -> &mut Self {
+        self.apply(&InputEvent::SetBool {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    /// Sets the number input `name`.
+    #[track_caller]
+    pub fn set_number(&mut self, name: &str, value: f32) -> &mut Self {
+        self.apply(&InputEvent::SetNumber {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    /// Fires the trigger input `name`.
+    #[track_caller]
+    pub fn fire(&mut self, name: &str) -> &mut Self {
+        self.apply(&InputEvent::Fire {
+            name: name.to_owned(),
+        })
+    }
+
+    /// Sends a pointer down event at `(x, y)` in artboard space.
+    pub fn pointer_down(&mut self, x: f32, y: f32) -> &mut Self {
+        self.state_machine.pointer_down_artboard(x, y);
+        self
+    }
+
+    /// Sends a pointer move event to `(x, y)` in artboard space.
+    pub fn pointer_move(&mut self, x: f32, y: f32) -> &mut Self {
+        self.state_machine.pointer_move_artboard(x, y);
+        self
+    }
+
+    /// Sends a pointer up event at `(x, y)` in artboard space.
+    pub fn pointer_up(&mut self, x: f32, y: f32) -> &mut Self {
+        self.state_machine.pointer_up_artboard(x, y);
+        self
+    }
+
+    /// Sends a pointer down followed by a pointer up event at `(x, y)` in artboard space.
+    pub fn click(&mut self, x: f32, y: f32) -> &mut Self {
+        self.pointer_down(x, y).pointer_up(x, y)
+    }
+
+    /// Replays `recording` with its fixed timestep, collecting the events reported during the
+    /// whole replay.
+    pub fn replay(&mut self, recording: Recording) -> &mut Self {
+        let mut replayer = Replayer::new(recording);
+        let mut events = Vec::new();
+
+        loop {
+            let start = replayer.time();
+            let is_replaying = replayer.advance(&mut self.state_machine);
+            let elapsed = replayer.time() - start;
+
+            if !elapsed.is_zero() {
+                self.time += elapsed;
+
+                for change in self.state_machine.state_changes() {
+                    self.states.insert(change.layer, change.state);
+                }
+
+                events.extend(self.state_machine.events());
+            }
+
+            if !is_replaying {
+                break;
+            }
+        }
+
+        self.events = events;
+
+        self
+    }
+
+    /// Asserts that `layer` is in `state`.
+    #[track_caller]
+    pub fn expect_state(&mut self, layer: &str, state: &str) -> &mut Self {
+        match self.state(layer) {
+            Some(current) => assert_eq!(
+                current, state,
+                "expected layer {layer:?} to be in state {state:?} at {:?}",
+                self.time
+            ),
+            None => panic!(
+                "expected layer {layer:?} to be in state {state:?} at {:?}, but it hasn't \
+                 entered any state; known layers: {:?}",
+                self.time,
+                self.states.keys().collect::<Vec<_>>()
+            ),
+        }
+
+        self
+    }
+
+    /// Asserts that an event named `name` was reported since the last advance.
+    #[track_caller]
+    pub fn expect_event(&mut self, name: &str) -> &mut Self {
+        assert!(
+            self.events.iter().any(|event| event.name == name),
+            "expected event {name:?} to be reported at {:?}, got {:?}",
+            self.time,
+            EventNames(&self.events)
+        );
+
+        self
+    }
+
+    /// Asserts that no event named `name` was reported since the last advance.
+    #[track_caller]
+    pub fn expect_no_event(&mut self, name: &str) -> &mut Self {
+        assert!(
+            self.events.iter().all(|event| event.name != name),
+            "expected event {name:?} not to be reported at {:?}",
+            self.time
+        );
+
+        self
+    }
+
+    /// Asserts that no events at all were reported since the last advance.
+    #[track_caller]
+    pub fn expect_no_events(&mut self) -> &mut Self {
+        assert!(
+            self.events.is_empty(),
+            "expected no events to be reported at {:?}, got {:?}",
+            self.time,
+            EventNames(&self.events)
+        );
+
+        self
+    }
+
+    /// Asserts that the bool input `name` is `value`.
+    #[track_caller]
+    pub fn expect_bool(&mut self, name: &str, value: bool) -> &mut Self {
+        assert_eq!(
+            self.state_machine.bool_value(name),
+            Some(value),
+            "unexpected value of bool input {name:?} at {:?}",
+            self.time
+        );
+
+        self
+    }
+
+    /// Asserts that the number input `name` is within `f32::EPSILON` of `value`.
+    #[track_caller]
+    pub fn expect_number(&mut self, name: &str, value: f32) -> &mut Self {
+        let current = self.state_machine.number_value(name);
+
+        assert!(
+            current.is_some_and(
+                |current| current >= value - f32::EPSILON && current <= value + f32::EPSILON
+            ),
+            "expected number input {name:?} to be {value} at {:?}, got {current:?}",
+            self.time
+        );
+
+        self
+    }
+}
+
+impl fmt::Debug for StateMachineTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateMachineTest")
+            .field("name", &self.state_machine.name())
+            .field("time", &self.time)
+            .field("states", &self.states)
+            .field("events", &EventNames(&self.events))
+            .finish()
+    }
+}
+
+struct EventNames<'e>(&'e [Event]);
+
+impl fmt::Debug for EventNames<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|event| &event.name))
+            .finish()
+    }
+}
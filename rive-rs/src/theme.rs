@@ -0,0 +1,96 @@
+use alloc::vec::Vec;
+
+use crate::renderer::Color;
+
+/// A color remap table that reskins artboards without editing the `.riv` file.
+///
+/// Every solid color and gradient stop whose color matches one of the remapped colors exactly is
+/// replaced. Use [`Artboard::palette`](crate::Artboard::palette) to find out which colors an
+/// artboard uses.
+///
+/// Applying a theme to a [`File`](crate::File) recolors every artboard instantiated from it
+/// afterwards, while applying it to an [`Artboard`](crate::Artboard) only recolors that instance.
+/// Color keyframes are not remapped, so animated colors keep their authored values.
+///
+/// ```
+/// # use rive_rs::{renderer::Color, Theme};
+/// let brand = Theme::new().with(
+///     Color { r: 0xff, g: 0x00, b: 0x00, a: 0xff },
+///     Color { r: 0x00, g: 0x66, b: 0xff, a: 0xff },
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Theme {
+    from: Vec<Color>,
+    to: Vec<Color>,
+}
+
+impl Theme {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remaps `from` to `to`, replacing any previous remap of `from`.
+    #[inline]
+    pub fn with(mut self, from: Color, to: Color) -> Self {
+        self.insert(from, to);
+        self
+    }
+
+    /// Remaps `from` to `to`, returning the color `from` was previously remapped to.
+    pub fn insert(&mut self, from: Color, to: Color) -> Option<Color> {
+        match self.from.iter().position(|&color| color == from) {
+            Some(i) => Some(core::mem::replace(&mut self.to[i], to)),
+            None => {
+                self.from.push(from);
+                self.to.push(to);
+
+                None
+            }
+        }
+    }
+
+    /// Returns the color `from` is remapped to.
+    #[inline]
+    pub fn get(&self, from: Color) -> Option<Color> {
+        self.from
+            .iter()
+            .position(|&color| color == from)
+            .map(|i| self.to[i])
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.from.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.from.is_empty()
+    }
+
+    pub(crate) fn from(&self) -> &[Color] {
+        &self.from
+    }
+
+    pub(crate) fn to(&self) -> &[Color] {
+        &self.to
+    }
+}
+
+impl Extend<(Color, Color)> for Theme {
+    fn extend<T: IntoIterator<Item = (Color, Color)>>(&mut self, iter: T) {
+        for (from, to) in iter {
+            self.insert(from, to);
+        }
+    }
+}
+
+impl FromIterator<(Color, Color)> for Theme {
+    fn from_iter<T: IntoIterator<Item = (Color, Color)>>(iter: T) -> Self {
+        let mut theme = Self::new();
+        theme.extend(iter);
+        theme
+    }
+}
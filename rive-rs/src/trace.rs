@@ -0,0 +1,10 @@
+/// Enters a `tracing` span named `$name` until the end of the enclosing block. Expands to nothing
+/// unless the `tracing` feature is enabled.
+macro_rules! span {
+    ( $name:expr ) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::trace_span!($name).entered();
+    };
+}
+
+pub(crate) use span;
@@ -0,0 +1,126 @@
+//! Interpolation of values over time, e.g. to smoothly drive number inputs from application
+//! data with [`StateMachine::tween`](crate::StateMachine::tween).
+
+use core::time::Duration;
+
+/// Easing curve applied to the progress of a [`Tween`].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Cubic ease in, starting slowly.
+    EaseIn,
+    /// Cubic ease out, ending slowly.
+    EaseOut,
+    /// Cubic ease in and out, starting and ending slowly.
+    EaseInOut,
+    /// Custom curve mapping progress in `[0.0, 1.0]` to the interpolation factor.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Returns the interpolation factor for `t` in `[0.0, 1.0]`.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => {
+                let t = 1.0 - t;
+                1.0 - t * t * t
+            }
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let t = 2.0 - 2.0 * t;
+                    1.0 - t * t * t / 2.0
+                }
+            }
+            Self::Custom(f) => f(t),
+        }
+    }
+}
+
+/// A value interpolated from `from` to `to` over `duration`.
+///
+/// ```
+/// # use core::time::Duration;
+/// # use rive_rs::tween::{Easing, Tween};
+/// let mut tween = Tween::new(0.0, 100.0, Duration::from_secs(2), Easing::Linear);
+///
+/// assert_eq!(tween.advance(Duration::from_secs(1)), 50.0);
+/// assert_eq!(tween.advance(Duration::from_secs(5)), 100.0);
+/// assert!(tween.is_finished());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tween {
+    from: f32,
+    to: f32,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl Tween {
+    #[inline]
+    pub fn new(from: f32, to: f32, duration: Duration, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: Duration::ZERO,
+            easing,
+        }
+    }
+
+    #[inline]
+    pub fn from(&self) -> f32 {
+        self.from
+    }
+
+    #[inline]
+    pub fn to(&self) -> f32 {
+        self.to
+    }
+
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    #[inline]
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Returns the progress of the tween in `[0.0, 1.0]`, before easing.
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f32 {
+        let t = self.progress();
+
+        if t >= 1.0 {
+            return self.to;
+        }
+
+        self.from + (self.to - self.from) * self.easing.apply(t)
+    }
+
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advances the tween by `elapsed` and returns the new value.
+    pub fn advance(&mut self, elapsed: Duration) -> f32 {
+        self.elapsed = self.elapsed.saturating_add(elapsed).min(self.duration);
+        self.value()
+    }
+}
@@ -0,0 +1,374 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use vello::{
+    kurbo::{Affine, BezPath, Point, Rect, Shape, Vec2},
+    peniko::{self, BlendMode, Brush, BrushRef, Fill, Mix},
+    SceneBuilder,
+};
+
+use crate::renderer;
+
+use super::{
+    sampling::{ImageQuality, Variants},
+    to_vello_mix, triangle_path,
+    util::{self, ScaleFromOrigin},
+    RenderStyle,
+};
+
+static NEXT_VERSION: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a new version, unique across all paths, paints, images, and buffers, so that a stale
+/// version is never mistaken for a current one even if the memory of a dropped object is reused.
+pub(super) fn next_version() -> u64 {
+    NEXT_VERSION.fetch_add(1, Ordering::Relaxed)
+}
+
+fn transform_bits(transform: Affine) -> [u64; 6] {
+    transform.as_coeffs().map(f64::to_bits)
+}
+
+/// Identifies what a [`Command`] draws without holding on to its data.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(super) enum Key {
+    PushClip {
+        transform: [u64; 6],
+        path: u64,
+    },
+    PopLayer,
+    Path {
+        transform: [u64; 6],
+        path: u64,
+        paint: u64,
+    },
+    Image {
+        transform: [u64; 6],
+        image: u64,
+        quality: ImageQuality,
+        blend_mode: renderer::BlendMode,
+        opacity: u32,
+    },
+    ImageMesh {
+        transform: [u64; 6],
+        image: u64,
+        vertices: u64,
+        uvs: u64,
+        indices: u64,
+        quality: ImageQuality,
+        blend_mode: renderer::BlendMode,
+        opacity: u32,
+    },
+}
+
+/// A drawing operation with everything needed to encode it later, e.g. after the path it draws
+/// was dropped.
+#[derive(Debug)]
+pub(super) enum Command {
+    PushClip {
+        transform: Affine,
+        path: Arc<BezPath>,
+    },
+    PopLayer,
+    Path {
+        transform: Affine,
+        path: Arc<BezPath>,
+        fill: Fill,
+        style: RenderStyle,
+        brush: Brush,
+        blend_mode: BlendMode,
+    },
+    Image {
+        transform: Affine,
+        image: peniko::Image,
+        variants: Arc<Variants>,
+        quality: ImageQuality,
+        blend_mode: renderer::BlendMode,
+        opacity: f32,
+    },
+    ImageMesh {
+        transform: Affine,
+        image: peniko::Image,
+        variants: Arc<Variants>,
+        vertices: Arc<Vec<u8>>,
+        uvs: Arc<Vec<u8>>,
+        indices: Arc<Vec<u8>>,
+        quality: ImageQuality,
+        blend_mode: renderer::BlendMode,
+        opacity: f32,
+    },
+}
+
+impl Command {
+    pub(super) fn push_clip(transform: Affine, path: &super::Path) -> (Key, Self) {
+        (
+            Key::PushClip {
+                transform: transform_bits(transform),
+                path: path.version,
+            },
+            Command::PushClip {
+                transform,
+                path: path.inner.clone(),
+            },
+        )
+    }
+
+    pub(super) fn path(transform: Affine, path: &super::Path, paint: &super::Paint) -> (Key, Self) {
+        (
+            Key::Path {
+                transform: transform_bits(transform),
+                path: path.version,
+                paint: paint.version,
+            },
+            Command::Path {
+                transform,
+                path: path.inner.clone(),
+                fill: path.fill,
+                style: paint.style.clone(),
+                brush: paint.brush.clone(),
+                blend_mode: paint.blend_mode,
+            },
+        )
+    }
+
+    pub(super) fn image(
+        transform: Affine,
+        image: &super::Image,
+        quality: ImageQuality,
+        blend_mode: renderer::BlendMode,
+        opacity: f32,
+    ) -> (Key, Self) {
+        (
+            Key::Image {
+                transform: transform_bits(transform),
+                image: image.version,
+                quality,
+                blend_mode,
+                opacity: opacity.to_bits(),
+            },
+            Command::Image {
+                transform,
+                image: image.inner.clone(),
+                variants: image.variants.clone(),
+                quality,
+                blend_mode,
+                opacity,
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn image_mesh(
+        transform: Affine,
+        image: &super::Image,
+        vertices: &super::Buffer,
+        uvs: &super::Buffer,
+        indices: &super::Buffer,
+        quality: ImageQuality,
+        blend_mode: renderer::BlendMode,
+        opacity: f32,
+    ) -> (Key, Self) {
+        (
+            Key::ImageMesh {
+                transform: transform_bits(transform),
+                image: image.version,
+                vertices: vertices.version,
+                uvs: uvs.version,
+                indices: indices.version,
+                quality,
+                blend_mode,
+                opacity: opacity.to_bits(),
+            },
+            Command::ImageMesh {
+                transform,
+                image: image.inner.clone(),
+                variants: image.variants.clone(),
+                vertices: vertices.inner.clone(),
+                uvs: uvs.inner.clone(),
+                indices: indices.inner.clone(),
+                quality,
+                blend_mode,
+                opacity,
+            },
+        )
+    }
+
+    pub(super) fn encode(&self, builder: &mut SceneBuilder<'_>) {
+        match self {
+            Command::PushClip { transform, path } => encode_clip(builder, *transform, path),
+            Command::PopLayer => builder.pop_layer(),
+            Command::Path {
+                transform,
+                path,
+                fill,
+                style,
+                brush,
+                blend_mode,
+            } => encode_path(builder, *transform, path, *fill, style, brush, *blend_mode),
+            Command::Image {
+                transform,
+                image,
+                variants,
+                quality,
+                blend_mode,
+                opacity,
+            } => encode_image(
+                builder,
+                *transform,
+                image,
+                variants,
+                *quality,
+                *blend_mode,
+                *opacity,
+            ),
+            Command::ImageMesh {
+                transform,
+                image,
+                variants,
+                vertices,
+                uvs,
+                indices,
+                quality,
+                blend_mode,
+                opacity,
+            } => encode_image_mesh(
+                builder,
+                *transform,
+                image,
+                variants,
+                vertices,
+                uvs,
+                indices,
+                *quality,
+                *blend_mode,
+                *opacity,
+            ),
+        }
+    }
+}
+
+// The `encode_*` functions below are also called directly with borrowed data when no group is
+// being recorded, so that drawing without a cache doesn't build commands at all.
+
+pub(super) fn encode_clip(builder: &mut SceneBuilder<'_>, transform: Affine, path: &BezPath) {
+    builder.push_layer(Mix::Clip, 1.0, transform, path);
+}
+
+pub(super) fn encode_path(
+    builder: &mut SceneBuilder<'_>,
+    transform: Affine,
+    path: &BezPath,
+    fill: Fill,
+    style: &RenderStyle,
+    brush: &Brush,
+    blend_mode: BlendMode,
+) {
+    let skip_blending = blend_mode == Mix::Normal.into();
+
+    if !skip_blending {
+        builder.push_layer(blend_mode, 1.0, transform, &path.bounding_box());
+    }
+
+    match style {
+        RenderStyle::Fill => builder.fill(fill, transform, brush, None, path),
+        RenderStyle::Stroke(stroke) => builder.stroke(stroke, transform, brush, None, path),
+    }
+
+    if !skip_blending {
+        builder.pop_layer();
+    }
+}
+
+pub(super) fn encode_image(
+    builder: &mut SceneBuilder<'_>,
+    transform: Affine,
+    image: &peniko::Image,
+    variants: &Variants,
+    quality: ImageQuality,
+    blend_mode: renderer::BlendMode,
+    opacity: f32,
+) {
+    let (variant, to_image) = variants.select(image, quality, transform);
+    let mix: Mix = to_vello_mix(blend_mode);
+
+    let transform = transform.pre_translate(Vec2::new(
+        image.width as f64 * -0.5,
+        image.height as f64 * -0.5,
+    ));
+    let rect = Rect::new(0.0, 0.0, image.width as f64, image.height as f64);
+
+    let skip_blending = mix == Mix::Normal && opacity == 1.0;
+
+    if skip_blending {
+        builder.push_layer(mix, opacity, transform, &rect);
+    }
+
+    builder.draw_image(&variant, transform * to_image);
+
+    if skip_blending {
+        builder.pop_layer();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn encode_image_mesh(
+    builder: &mut SceneBuilder<'_>,
+    transform: Affine,
+    image: &peniko::Image,
+    variants: &Variants,
+    vertices: &[u8],
+    uvs: &[u8],
+    indices: &[u8],
+    quality: ImageQuality,
+    blend_mode: renderer::BlendMode,
+    opacity: f32,
+) {
+    let vertices: &[[f32; 2]] = bytemuck::cast_slice(vertices);
+    let uvs: &[[f32; 2]] = bytemuck::cast_slice(uvs);
+    let indices: &[u16] = bytemuck::cast_slice(indices);
+
+    let mix: Mix = to_vello_mix(blend_mode);
+
+    for triangle_indices in indices.chunks_exact(3) {
+        let points = [
+            vertices[triangle_indices[0] as usize],
+            vertices[triangle_indices[1] as usize],
+            vertices[triangle_indices[2] as usize],
+        ];
+        let uvs = [
+            uvs[triangle_indices[0] as usize],
+            uvs[triangle_indices[1] as usize],
+            uvs[triangle_indices[2] as usize],
+        ];
+
+        let center = Point::new(
+            ((points[0][0] + points[1][0] + points[2][0]) / 3.0) as f64,
+            ((points[0][1] + points[1][1] + points[2][1]) / 3.0) as f64,
+        );
+
+        let path = triangle_path(points.map(|v| Point::new(v[0] as f64, v[1] as f64)));
+
+        let transform = transform.pre_scale_from_origin(1.03, center);
+        let brush_transform = util::map_uvs_to_triangle(&points, &uvs, image.width, image.height);
+        let (variant, to_image) = variants.select(image, quality, transform * brush_transform);
+
+        let skip_blending = mix == Mix::Normal;
+
+        if !skip_blending {
+            builder.push_layer(mix, opacity, transform, &path.bounding_box());
+        }
+
+        builder.fill(
+            Fill::NonZero,
+            transform,
+            BrushRef::Image(&variant),
+            Some(brush_transform * to_image),
+            &path,
+        );
+
+        if !skip_blending {
+            builder.pop_layer();
+        }
+    }
+}
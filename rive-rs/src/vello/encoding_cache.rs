@@ -0,0 +1,121 @@
+use std::{collections::HashMap, fmt, mem};
+
+use vello::SceneFragment;
+
+use super::command::{Command, Key};
+
+/// Drawing commands of a group between a [`state_push`] and its matching [`state_pop`] that
+/// have not been encoded yet.
+///
+/// [`state_push`]: crate::renderer::Renderer::state_push
+/// [`state_pop`]: crate::renderer::Renderer::state_pop
+#[derive(Debug)]
+pub(super) struct Group {
+    pub(super) depth: usize,
+    pub(super) keys: Vec<Key>,
+    pub(super) commands: Vec<Command>,
+}
+
+impl Group {
+    pub(super) fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            keys: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    pub(super) fn push(&mut self, (key, command): (Key, Command)) {
+        self.keys.push(key);
+        self.commands.push(command);
+    }
+}
+
+/// Encoded drawables kept across frames, so that the parts of a scene that didn't change are
+/// not encoded again.
+///
+/// This only saves the CPU work of encoding: cached groups are appended to the frame's scene as
+/// Vello encodings and are still rasterized by the GPU every frame. Nothing is rendered to an
+/// intermediate texture.
+///
+/// Rive draws every drawable between a [`state_push`] and a [`state_pop`]. A
+/// [`Renderer`](super::Renderer) created with [`Renderer::with_encoding_cache`] records the drawing
+/// commands of every such group that doesn't contain other groups and looks them up in the
+/// cache when the group is closed. Paths, paints, images, and buffers are versioned every time
+/// they are changed, so a group is only reused if it draws exactly the same things with the
+/// same transforms as before. Encoding big illustrations where only a small part animates then
+/// mostly amounts to copying from the cache.
+///
+/// Entries that were not used during a frame are evicted at the start of the next one.
+///
+/// ```no_run
+/// # use rive_rs::{scene::Scene as _, vello::EncodingCache, Renderer, Viewport};
+/// # fn frames(scene: &mut dyn rive_rs::Scene, viewport: &mut Viewport) {
+/// let mut renderer = Renderer::with_encoding_cache(EncodingCache::new());
+///
+/// loop {
+///     renderer.reset();
+///     scene.draw_in(&mut renderer, viewport);
+///     // Append `renderer.scene()` to the frame's Vello scene.
+/// }
+/// # }
+/// ```
+///
+/// [`state_push`]: crate::renderer::Renderer::state_push
+/// [`state_pop`]: crate::renderer::Renderer::state_pop
+/// [`Renderer::with_encoding_cache`]: super::Renderer::with_encoding_cache
+#[derive(Default)]
+pub struct EncodingCache {
+    previous: HashMap<Vec<Key>, SceneFragment>,
+    current: HashMap<Vec<Key>, SceneFragment>,
+}
+
+impl EncodingCache {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached groups.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.previous.clear();
+        self.current.clear();
+    }
+
+    /// Evicts the groups that were not used since the last call.
+    pub(super) fn start_frame(&mut self) {
+        self.previous = mem::take(&mut self.current);
+    }
+
+    pub(super) fn get(&mut self, keys: &[Key]) -> Option<&SceneFragment> {
+        if !self.current.contains_key(keys) {
+            let (keys, fragment) = self.previous.remove_entry(keys)?;
+            self.current.insert(keys, fragment);
+        }
+
+        self.current.get(keys)
+    }
+
+    pub(super) fn insert(&mut self, keys: Vec<Key>, fragment: SceneFragment) {
+        self.current.insert(keys, fragment);
+    }
+}
+
+impl fmt::Debug for EncodingCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodingCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -1,20 +1,37 @@
-use std::{fmt, io::Cursor};
+use std::{fmt, io::Cursor, sync::Arc};
 
 use image::io::Reader;
 use smallvec::SmallVec;
 use vello::{
-    kurbo::{Affine, BezPath, Cap, Join, Line, PathSeg, Point, Rect, Shape, Stroke, Vec2},
+    kurbo::{Affine, BezPath, Cap, Join, Line, PathSeg, Point, Rect, Stroke},
     peniko::{
-        self, BlendMode, Brush, BrushRef, Color, ColorStop, ColorStopsSource, Fill, Format, Mix,
+        self, BlendMode, Brush, Color, ColorStop, ColorStopsSource, Compose, Fill, Format, Mix,
     },
     SceneBuilder, SceneFragment,
 };
 
+mod command;
+mod encoding_cache;
+#[cfg(feature = "wgpu-profiler")]
+mod profile;
+mod sampling;
+mod snapshot;
+mod texture;
+mod thumbnail;
 mod util;
 
-use util::ScaleFromOrigin;
+use command::{next_version, Command, Key};
+use encoding_cache::Group;
+use sampling::Variants;
 
-use crate::renderer;
+use crate::renderer::{self, IntoTransform};
+
+pub use encoding_cache::EncodingCache;
+#[cfg(feature = "wgpu-profiler")]
+pub use profile::{FrameProfile, LastFrameProfile, PassTiming};
+pub use sampling::ImageQuality;
+pub use snapshot::{snapshot, snapshot_at};
+pub use texture::TextureRenderer;
 
 fn to_vello_color(color: renderer::Color) -> Color {
     Color::rgba8(color.r, color.g, color.b, color.a)
@@ -54,7 +71,8 @@ fn triangle_path(points: [Point; 3]) -> BezPath {
 
 #[derive(Debug)]
 pub struct Buffer {
-    inner: Vec<u8>,
+    inner: Arc<Vec<u8>>,
+    version: u64,
 }
 
 impl Buffer {
@@ -77,13 +95,15 @@ impl renderer::Buffer for Buffer {
         len_in_bytes: usize,
     ) -> Self {
         Self {
-            inner: vec![0; len_in_bytes],
+            inner: Arc::new(vec![0; len_in_bytes]),
+            version: next_version(),
         }
     }
 
     #[inline]
     fn map(&mut self) -> &mut [u8] {
-        &mut self.inner
+        self.version = next_version();
+        Arc::make_mut(&mut self.inner).as_mut_slice()
     }
 
     #[inline]
@@ -92,8 +112,16 @@ impl renderer::Buffer for Buffer {
 
 #[derive(Debug)]
 pub struct Path {
-    inner: BezPath,
+    inner: Arc<BezPath>,
     fill: Fill,
+    version: u64,
+}
+
+impl Path {
+    fn inner_mut(&mut self) -> &mut BezPath {
+        self.version = next_version();
+        Arc::make_mut(&mut self.inner)
+    }
 }
 
 impl Default for Path {
@@ -102,6 +130,7 @@ impl Default for Path {
         Self {
             inner: Default::default(),
             fill: Fill::NonZero,
+            version: next_version(),
         }
     }
 }
@@ -133,19 +162,20 @@ impl renderer::Path for Path {
 
     #[inline]
     fn reset(&mut self) {
-        self.inner.truncate(0);
+        self.inner_mut().truncate(0);
     }
 
     #[inline]
     fn extend(&mut self, from: &Self, transform: &[f32; 6]) {
-        let mut from = from.inner.clone();
+        let mut from = (*from.inner).clone();
         from.apply_affine(Affine::new(transform.map(Into::into)));
 
-        self.inner.extend(from.elements().iter().cloned());
+        self.inner_mut().extend(from.elements().iter().cloned());
     }
 
     #[inline]
     fn set_fill_rule(&mut self, fill_rule: crate::path::FillRule) {
+        self.version = next_version();
         self.fill = match fill_rule {
             crate::path::FillRule::NonZero => Fill::NonZero,
             crate::path::FillRule::EvenOdd => Fill::EvenOdd,
@@ -154,17 +184,17 @@ impl renderer::Path for Path {
 
     #[inline]
     fn move_to(&mut self, x: f32, y: f32) {
-        self.inner.move_to(Point::new(x as f64, y as f64));
+        self.inner_mut().move_to(Point::new(x as f64, y as f64));
     }
 
     #[inline]
     fn line_to(&mut self, x: f32, y: f32) {
-        self.inner.line_to(Point::new(x as f64, y as f64));
+        self.inner_mut().line_to(Point::new(x as f64, y as f64));
     }
 
     #[inline]
     fn cubic_to(&mut self, ox: f32, oy: f32, ix: f32, iy: f32, x: f32, y: f32) {
-        self.inner.curve_to(
+        self.inner_mut().curve_to(
             Point::new(ox as f64, oy as f64),
             Point::new(ix as f64, iy as f64),
             Point::new(x as f64, y as f64),
@@ -173,7 +203,7 @@ impl renderer::Path for Path {
 
     #[inline]
     fn close(&mut self) {
-        self.inner.close_path();
+        self.inner_mut().close_path();
     }
 }
 
@@ -197,7 +227,7 @@ impl ColorStopsSource for SliceStops<'_> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum RenderStyle {
     Fill,
     Stroke(Stroke),
@@ -208,6 +238,7 @@ pub struct Paint {
     style: RenderStyle,
     brush: Brush,
     blend_mode: BlendMode,
+    version: u64,
 }
 
 impl Default for Paint {
@@ -217,6 +248,7 @@ impl Default for Paint {
             style: RenderStyle::Fill,
             brush: Brush::Solid(Color::TRANSPARENT),
             blend_mode: Mix::Normal.into(),
+            version: next_version(),
         }
     }
 }
@@ -226,6 +258,7 @@ impl renderer::Paint for Paint {
 
     #[inline]
     fn set_style(&mut self, style: renderer::PaintStyle) {
+        self.version = next_version();
         self.style = match style {
             renderer::PaintStyle::Stroke => RenderStyle::Stroke(Stroke::new(0.0)),
             renderer::PaintStyle::Fill => RenderStyle::Fill,
@@ -234,11 +267,14 @@ impl renderer::Paint for Paint {
 
     #[inline]
     fn set_color(&mut self, color: renderer::Color) {
+        self.version = next_version();
         self.brush = Brush::Solid(to_vello_color(color));
     }
 
     #[inline]
     fn set_thickness(&mut self, thickness: f32) {
+        self.version = next_version();
+
         loop {
             if let RenderStyle::Stroke(stroke) = &mut self.style {
                 stroke.width = thickness as f64;
@@ -251,6 +287,8 @@ impl renderer::Paint for Paint {
 
     #[inline]
     fn set_join(&mut self, join: renderer::StrokeJoin) {
+        self.version = next_version();
+
         loop {
             if let RenderStyle::Stroke(stroke) = &mut self.style {
                 stroke.join = match join {
@@ -267,6 +305,8 @@ impl renderer::Paint for Paint {
 
     #[inline]
     fn set_cap(&mut self, cap: renderer::StrokeCap) {
+        self.version = next_version();
+
         loop {
             if let RenderStyle::Stroke(stroke) = &mut self.style {
                 stroke.start_cap = match cap {
@@ -284,11 +324,13 @@ impl renderer::Paint for Paint {
 
     #[inline]
     fn set_blend_mode(&mut self, blend_mode: renderer::BlendMode) {
+        self.version = next_version();
         self.blend_mode = to_vello_mix(blend_mode).into();
     }
 
     #[inline]
     fn set_gradient(&mut self, gradient: &Self::Gradient) {
+        self.version = next_version();
         self.brush = Brush::Gradient(gradient.inner.clone());
     }
 
@@ -336,6 +378,8 @@ impl renderer::Gradient for Gradient {
 #[derive(Debug)]
 pub struct Image {
     inner: peniko::Image,
+    variants: Arc<Variants>,
+    version: u64,
 }
 
 impl renderer::Image for Image {
@@ -351,26 +395,119 @@ impl renderer::Image for Image {
 
         Some(Image {
             inner: peniko::Image::new(image.into_raw().into(), Format::Rgba8, width, height),
+            variants: Arc::default(),
+            version: next_version(),
         })
     }
 }
 
+/// Encodes everything drawn into it into a Vello [`SceneFragment`].
+///
+/// Renderers are meant to be kept around and [reset](Renderer::reset) at the start of every
+/// frame, which keeps the buffers of the encoding allocated instead of growing them from scratch
+/// every time.
 pub struct Renderer {
     scene: Box<SceneFragment>,
     builder: SceneBuilder<'static>,
     transforms: Vec<Affine>,
     clips: Vec<bool>,
+    layers: Vec<Option<(Color, Affine, Rect)>>,
+    image_quality: ImageQuality,
+    encoding_cache: Option<EncodingCache>,
+    group: Option<Group>,
 }
 
 impl Renderer {
+    /// Creates a renderer that reuses the drawables encoded in earlier frames from
+    /// `encoding_cache`, see [`EncodingCache`].
+    pub fn with_encoding_cache(mut encoding_cache: EncodingCache) -> Self {
+        encoding_cache.start_frame();
+
+        Self {
+            encoding_cache: Some(encoding_cache),
+            ..Self::default()
+        }
+    }
+
+    /// Clears everything drawn so far and the transform and clip stacks, keeping the allocated
+    /// buffers and the [image quality](Renderer::set_image_quality) for the next frame.
+    ///
+    /// With an [`EncodingCache`], this also starts a new frame of the cache, evicting the groups
+    /// that were not used since the previous reset.
+    pub fn reset(&mut self) {
+        self.transforms.clear();
+        self.transforms.push(Affine::IDENTITY);
+        self.clips.clear();
+        self.clips.push(false);
+        self.layers.clear();
+        self.group = None;
+
+        if let Some(encoding_cache) = &mut self.encoding_cache {
+            encoding_cache.start_frame();
+        }
+
+        // Creating a builder resets the fragment's encoding without freeing it.
+        self.builder = SceneBuilder::for_fragment(unsafe {
+            // Same hack as in `Renderer::default`.
+            std::mem::transmute::<&mut SceneFragment, &'static mut SceneFragment>(&mut *self.scene)
+        });
+    }
+
+    /// Encodes any drawable still being recorded and returns the encoding cache, to be passed to
+    /// the renderer of the next frame.
+    pub fn take_encoding_cache(&mut self) -> Option<EncodingCache> {
+        self.flush_group();
+        self.encoding_cache.take()
+    }
+
+    /// Returns the scene drawn so far.
+    ///
+    /// With an [`EncodingCache`], a drawable is only added once the group it is drawn in is closed,
+    /// or after [`Renderer::take_encoding_cache`].
     pub fn scene(&self) -> &SceneFragment {
         &self.scene
     }
 
-    pub fn into_scene(self) -> SceneFragment {
+    pub fn into_scene(mut self) -> SceneFragment {
+        self.flush_group();
         *self.scene
     }
 
+    #[inline]
+    pub fn image_quality(&self) -> ImageQuality {
+        self.image_quality
+    }
+
+    /// Sets how images drawn from now on are filtered, e.g. [`ImageQuality::Nearest`] for pixel
+    /// art.
+    #[inline]
+    pub fn set_image_quality(&mut self, image_quality: ImageQuality) {
+        self.image_quality = image_quality;
+    }
+
+    /// Draws everything drawn into `instance` once for every transform in `transforms`, relative
+    /// to the current transform.
+    ///
+    /// Drawing a scene once into its own renderer and then instancing it avoids converting its
+    /// paths again for every copy, which makes drawing many identical copies of an artboard much
+    /// cheaper.
+    pub fn draw_instances<I>(&mut self, instance: &Renderer, transforms: I)
+    where
+        I: IntoIterator,
+        I::Item: IntoTransform,
+    {
+        self.flush_group();
+
+        let base = *self.last_transform();
+
+        for transform in transforms {
+            self.builder.append(
+                &instance.scene,
+                Some(base * Affine::new(transform.into_transform().map(Into::into))),
+            );
+        }
+    }
+
     fn last_transform(&mut self) -> &mut Affine {
         self.transforms.last_mut().unwrap()
     }
@@ -378,6 +515,50 @@ impl Renderer {
     fn last_clip(&mut self) -> &mut bool {
         self.clips.last_mut().unwrap()
     }
+
+    /// Pops the layer of the current clip right away, or records it if a group is open.
+    fn pop_clip(&mut self) {
+        match &mut self.group {
+            Some(group) => group.push((Key::PopLayer, Command::PopLayer)),
+            None => self.builder.pop_layer(),
+        }
+    }
+
+    /// Encodes the commands of the open group without caching them, e.g. because it turned out
+    /// to contain other groups.
+    fn flush_group(&mut self) {
+        if let Some(group) = self.group.take() {
+            for command in &group.commands {
+                command.encode(&mut self.builder);
+            }
+        }
+    }
+
+    /// Appends the open group from the cache, or encodes and caches it if it's not there.
+    fn close_group(&mut self) {
+        let (Some(group), Some(encoding_cache)) = (self.group.take(), &mut self.encoding_cache)
+        else {
+            return;
+        };
+
+        if group.commands.is_empty() {
+            return;
+        }
+
+        if let Some(fragment) = encoding_cache.get(&group.keys) {
+            self.builder.append(fragment, None);
+            return;
+        }
+
+        let mut fragment = SceneFragment::default();
+        let mut builder = SceneBuilder::for_fragment(&mut fragment);
+        for command in &group.commands {
+            command.encode(&mut builder);
+        }
+
+        self.builder.append(&fragment, None);
+        encoding_cache.insert(group.keys, fragment);
+    }
 }
 
 impl Default for Renderer {
@@ -398,6 +579,10 @@ impl Default for Renderer {
             builder,
             transforms: vec![Affine::IDENTITY],
             clips: vec![false],
+            layers: Vec::new(),
+            image_quality: ImageQuality::default(),
+            encoding_cache: None,
+            group: None,
         }
     }
 }
@@ -419,13 +604,27 @@ impl renderer::Renderer for Renderer {
 
         self.transforms.push(last_transform);
         self.clips.push(false);
+
+        if self.encoding_cache.is_some() {
+            // Only groups without nested groups are cached, so the enclosing one is encoded.
+            self.flush_group();
+            self.group = Some(Group::new(self.transforms.len()));
+        }
     }
 
     #[inline]
     fn state_pop(&mut self) {
         self.transforms.pop();
         if self.clips.pop().unwrap_or_default() {
-            self.builder.pop_layer();
+            self.pop_clip();
+        }
+
+        if self
+            .group
+            .as_ref()
+            .is_some_and(|group| self.transforms.len() < group.depth)
+        {
+            self.close_group();
         }
 
         if self.transforms.is_empty() {
@@ -445,11 +644,13 @@ impl renderer::Renderer for Renderer {
         let transform = *self.last_transform();
 
         if *self.last_clip() {
-            self.builder.pop_layer();
+            self.pop_clip();
         }
 
-        self.builder
-            .push_layer(Mix::Clip, 1.0, transform, &path.inner);
+        match &mut self.group {
+            Some(group) => group.push(Command::push_clip(transform, path)),
+            None => command::encode_clip(&mut self.builder, transform, &path.inner),
+        }
 
         *self.last_clip() = true;
     }
@@ -458,51 +659,41 @@ impl renderer::Renderer for Renderer {
     fn draw_path(&mut self, path: &Self::Path, paint: &Self::Paint) {
         let transform = *self.last_transform();
 
-        let builder = &mut self.builder;
-
-        let skip_blending = paint.blend_mode == Mix::Normal.into();
-
-        if !skip_blending {
-            builder.push_layer(paint.blend_mode, 1.0, transform, &path.inner.bounding_box());
-        }
-
-        match &paint.style {
-            RenderStyle::Fill => {
-                builder.fill(path.fill, transform, &paint.brush, None, &path.inner)
-            }
-            RenderStyle::Stroke(stroke) => {
-                builder.stroke(stroke, transform, &paint.brush, None, &path.inner)
-            }
-        }
-
-        if !skip_blending {
-            builder.pop_layer();
+        match &mut self.group {
+            Some(group) => group.push(Command::path(transform, path, paint)),
+            None => command::encode_path(
+                &mut self.builder,
+                transform,
+                &path.inner,
+                path.fill,
+                &paint.style,
+                &paint.brush,
+                paint.blend_mode,
+            ),
         }
     }
 
     #[inline]
     fn draw_image(&mut self, image: &Self::Image, blend_mode: renderer::BlendMode, opacity: f32) {
-        let image = &image.inner;
-        let mix: Mix = to_vello_mix(blend_mode);
-
-        let transform = self.last_transform().pre_translate(Vec2::new(
-            image.width as f64 * -0.5,
-            image.height as f64 * -0.5,
-        ));
-        let rect = Rect::new(0.0, 0.0, image.width as f64, image.height as f64);
-
-        let builder = &mut self.builder;
-
-        let skip_blending = mix == Mix::Normal && opacity == 1.0;
-
-        if skip_blending {
-            builder.push_layer(mix, opacity, transform, &rect);
-        }
-
-        builder.draw_image(image, transform);
+        let transform = *self.last_transform();
 
-        if skip_blending {
-            builder.pop_layer();
+        match &mut self.group {
+            Some(group) => group.push(Command::image(
+                transform,
+                image,
+                self.image_quality,
+                blend_mode,
+                opacity,
+            )),
+            None => command::encode_image(
+                &mut self.builder,
+                transform,
+                &image.inner,
+                &image.variants,
+                self.image_quality,
+                blend_mode,
+                opacity,
+            ),
         }
     }
 
@@ -516,55 +707,69 @@ impl renderer::Renderer for Renderer {
         blend_mode: renderer::BlendMode,
         opacity: f32,
     ) {
-        let image = &image.inner;
-        let vertices = vertices.as_f32_pairs();
-        let uvs = uvs.as_f32_pairs();
-
-        let mix: Mix = to_vello_mix(blend_mode);
-
-        for triangle_indices in indices.as_u16s().chunks_exact(3) {
-            let points = [
-                vertices[triangle_indices[0] as usize],
-                vertices[triangle_indices[1] as usize],
-                vertices[triangle_indices[2] as usize],
-            ];
-            let uvs = [
-                uvs[triangle_indices[0] as usize],
-                uvs[triangle_indices[1] as usize],
-                uvs[triangle_indices[2] as usize],
-            ];
-
-            let center = Point::new(
-                ((points[0][0] + points[1][0] + points[2][0]) / 3.0) as f64,
-                ((points[0][1] + points[1][1] + points[2][1]) / 3.0) as f64,
-            );
+        let transform = *self.last_transform();
 
-            let path = triangle_path(points.map(|v| Point::new(v[0] as f64, v[1] as f64)));
+        match &mut self.group {
+            Some(group) => group.push(Command::image_mesh(
+                transform,
+                image,
+                vertices,
+                uvs,
+                indices,
+                self.image_quality,
+                blend_mode,
+                opacity,
+            )),
+            None => command::encode_image_mesh(
+                &mut self.builder,
+                transform,
+                &image.inner,
+                &image.variants,
+                &vertices.inner,
+                &uvs.inner,
+                &indices.inner,
+                self.image_quality,
+                blend_mode,
+                opacity,
+            ),
+        }
+    }
 
-            let transform = self.last_transform().pre_scale_from_origin(1.03, center);
-            let brush_transform =
-                util::map_uvs_to_triangle(&points, &uvs, image.width, image.height);
+    #[inline]
+    fn push_layer(&mut self, width: f32, height: f32, opacity: f32, tint: Option<renderer::Color>) {
+        self.flush_group();
 
-            let builder = &mut self.builder;
+        let transform = *self.last_transform();
+        let rect = Rect::new(0.0, 0.0, width.into(), height.into());
 
-            let skip_blending = mix == Mix::Normal;
+        self.builder
+            .push_layer(Mix::Normal, opacity.clamp(0.0, 1.0), transform, &rect);
+        self.layers
+            .push(tint.map(|tint| (to_vello_color(tint), transform, rect)));
+    }
 
-            if !skip_blending {
-                builder.push_layer(mix, opacity, transform, &path.bounding_box());
-            }
+    #[inline]
+    fn pop_layer(&mut self) {
+        self.flush_group();
 
-            builder.fill(
-                Fill::NonZero,
+        let Some(layer) = self.layers.pop() else {
+            return;
+        };
+
+        if let Some((tint, transform, rect)) = layer {
+            // Multiplying with `SrcAtop` only tints pixels that were actually drawn.
+            self.builder.push_layer(
+                BlendMode::new(Mix::Multiply, Compose::SrcAtop),
+                1.0,
                 transform,
-                BrushRef::Image(image),
-                Some(brush_transform),
-                &path,
+                &rect,
             );
-
-            if !skip_blending {
-                builder.pop_layer();
-            }
+            self.builder
+                .fill(Fill::NonZero, transform, tint, None, &rect);
+            self.builder.pop_layer();
         }
+
+        self.builder.pop_layer();
     }
 }
 
@@ -573,6 +778,8 @@ impl fmt::Debug for Renderer {
         f.debug_struct("Renderer")
             .field("transforms", &self.transforms)
             .field("clips", &self.clips)
+            .field("layers", &self.layers)
+            .field("encoding_cache", &self.encoding_cache)
             .finish()
     }
 }
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use super::TextureRenderer;
+
+/// GPU time spent in one pass of a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PassTiming {
+    /// Name of the pass, e.g. `"coarse"` or `"fine"`.
+    pub label: String,
+    /// Time from the start of the frame's first pass until the start of this one.
+    pub start: Duration,
+    pub duration: Duration,
+    /// Number of passes this one is nested in.
+    pub depth: usize,
+}
+
+/// Timings of the GPU passes of a frame, as measured with timestamp queries.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameProfile {
+    passes: Vec<PassTiming>,
+    total: Duration,
+}
+
+impl FrameProfile {
+    fn new(renderer: &vello::Renderer) -> Option<Self> {
+        let results = renderer.profile_result.as_ref()?;
+
+        let frame_start = results
+            .iter()
+            .map(|result| result.time.start)
+            .min_by(f64::total_cmp)?;
+        let frame_end = results
+            .iter()
+            .map(|result| result.time.end)
+            .max_by(f64::total_cmp)?;
+
+        let seconds = |seconds: f64| Duration::from_secs_f64(seconds.max(0.0));
+
+        let mut passes = Vec::new();
+        let mut stack: Vec<_> = results.iter().rev().map(|result| (0, result)).collect();
+
+        while let Some((depth, result)) = stack.pop() {
+            passes.push(PassTiming {
+                label: result.label.clone(),
+                start: seconds(result.time.start - frame_start),
+                duration: seconds(result.time.end - result.time.start),
+                depth,
+            });
+
+            stack.extend(
+                result
+                    .nested_scopes
+                    .iter()
+                    .rev()
+                    .map(|result| (depth + 1, result)),
+            );
+        }
+
+        Some(Self {
+            passes,
+            total: seconds(frame_end - frame_start),
+        })
+    }
+
+    /// Returns the passes in the order they were recorded, each followed by the passes nested
+    /// in it.
+    #[inline]
+    pub fn passes(&self) -> &[PassTiming] {
+        &self.passes
+    }
+
+    /// Returns the GPU time from the start of the first pass to the end of the last one.
+    #[inline]
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+}
+
+/// Access to the GPU timings of the last frame rendered by a Vello renderer.
+///
+/// Timings are read back from the GPU a few frames late, so the profile usually describes a
+/// frame rendered slightly earlier than the last one submitted. Devices without the
+/// `TIMESTAMP_QUERY` feature never produce one.
+pub trait LastFrameProfile {
+    /// Takes the timings of the most recent frame that finished on the GPU, if any.
+    ///
+    /// Each profile is only returned once, so frames that haven't finished since the last call
+    /// return `None` instead of the same timings again.
+    fn take_last_frame_profile(&mut self) -> Option<FrameProfile>;
+}
+
+impl LastFrameProfile for vello::Renderer {
+    #[inline]
+    fn take_last_frame_profile(&mut self) -> Option<FrameProfile> {
+        let profile = FrameProfile::new(self);
+        self.profile_result = None;
+
+        profile
+    }
+}
+
+impl LastFrameProfile for TextureRenderer {
+    #[inline]
+    fn take_last_frame_profile(&mut self) -> Option<FrameProfile> {
+        self.renderer.take_last_frame_profile()
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use image::{
+    imageops::{self, FilterType},
+    RgbaImage,
+};
+use vello::{
+    kurbo::Affine,
+    peniko::{self, Format},
+};
+
+/// Largest factor images are upscaled by for [`ImageQuality::Nearest`].
+const MAX_NEAREST_SCALE: u32 = 8;
+/// Largest number of pixels of an image upscaled for [`ImageQuality::Nearest`], 64 MiB in RGBA.
+const MAX_NEAREST_PIXELS: u64 = 4096 * 4096;
+
+/// How images are filtered when drawn at a size other than their own, set with
+/// [`Renderer::set_image_quality`](super::Renderer::set_image_quality).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ImageQuality {
+    /// Nearest-neighbor filtering, which keeps pixel art crisp.
+    ///
+    /// Vello always filters bilinearly, so upscaled images are drawn from a copy enlarged on the
+    /// CPU by a power of two, up to 8 times per side. Images that would be enlarged past 4096 ×
+    /// 4096 pixels are enlarged less, or filtered bilinearly if they cannot be enlarged at all.
+    Nearest,
+    /// Bilinear filtering.
+    #[default]
+    Bilinear,
+    /// Bilinear filtering from the mip level closest to the drawn size, which avoids shimmering
+    /// when images are downscaled. Mip levels are generated on the CPU the first time they are
+    /// needed.
+    Mipmapped,
+}
+
+fn to_rgba(image: &peniko::Image) -> RgbaImage {
+    RgbaImage::from_raw(image.width, image.height, image.data.data().to_vec())
+        .expect("image data does not match its size")
+}
+
+fn to_peniko(image: RgbaImage) -> peniko::Image {
+    let (width, height) = image.dimensions();
+    peniko::Image::new(image.into_raw().into(), Format::Rgba8, width, height)
+}
+
+/// Variants of an image generated lazily for the different [`ImageQuality`] settings.
+#[derive(Debug, Default)]
+pub(super) struct Variants {
+    mips: OnceLock<Vec<peniko::Image>>,
+    upscaled: Mutex<BTreeMap<u32, peniko::Image>>,
+}
+
+impl Variants {
+    /// Returns the variant of `image` to draw with `transform` at `quality`, together with the
+    /// transform mapping it onto `image`.
+    pub(super) fn select(
+        &self,
+        image: &peniko::Image,
+        quality: ImageQuality,
+        transform: Affine,
+    ) -> (peniko::Image, Affine) {
+        let scale = transform.determinant().abs().sqrt();
+
+        let variant = match quality {
+            ImageQuality::Bilinear => None,
+            ImageQuality::Nearest => self.upscaled(image, scale),
+            ImageQuality::Mipmapped => self.mip(image, scale),
+        };
+
+        match variant {
+            Some(variant) => {
+                let to_image = Affine::scale_non_uniform(
+                    image.width as f64 / variant.width as f64,
+                    image.height as f64 / variant.height as f64,
+                );
+
+                (variant, to_image)
+            }
+            None => (image.clone(), Affine::IDENTITY),
+        }
+    }
+
+    fn upscaled(&self, image: &peniko::Image, scale: f64) -> Option<peniko::Image> {
+        // Powers of two keep the number of cached variants at log2(MAX_NEAREST_SCALE).
+        let mut factor = (scale.ceil() as u32)
+            .clamp(1, MAX_NEAREST_SCALE)
+            .next_power_of_two();
+        let pixels = u64::from(image.width) * u64::from(image.height);
+
+        while factor > 1 && pixels * u64::from(factor * factor) > MAX_NEAREST_PIXELS {
+            factor /= 2;
+        }
+
+        if factor == 1 {
+            return None;
+        }
+
+        let mut upscaled = self.upscaled.lock().unwrap_or_else(PoisonError::into_inner);
+        let variant = upscaled.entry(factor).or_insert_with(|| {
+            to_peniko(imageops::resize(
+                &to_rgba(image),
+                image.width * factor,
+                image.height * factor,
+                FilterType::Nearest,
+            ))
+        });
+
+        Some(variant.clone())
+    }
+
+    fn mip(&self, image: &peniko::Image, scale: f64) -> Option<peniko::Image> {
+        if !scale.is_normal() || scale >= 0.5 {
+            return None;
+        }
+
+        let mips = self.mips.get_or_init(|| {
+            let mut mips = Vec::new();
+            let mut mip = to_rgba(image);
+
+            while mip.width() > 1 || mip.height() > 1 {
+                mip = imageops::resize(
+                    &mip,
+                    (mip.width() / 2).max(1),
+                    (mip.height() / 2).max(1),
+                    FilterType::Triangle,
+                );
+                mips.push(to_peniko(mip.clone()));
+            }
+
+            mips
+        });
+
+        // Pick the smallest level that is still at least as large as the drawn image.
+        let level = (1.0 / scale).log2().floor() as usize;
+
+        mips.get(level.min(mips.len()).checked_sub(1)?).cloned()
+    }
+}
@@ -0,0 +1,40 @@
+use core::time::Duration;
+
+use vello::SceneFragment;
+
+use crate::{
+    linear_animation::LinearAnimation,
+    scene::{self, Viewport},
+};
+
+use super::Renderer;
+
+/// Draws the current pose of `scene` fitted into `viewport` into a standalone fragment.
+///
+/// The fragment can be kept and appended to a [`vello::SceneBuilder`] every frame, e.g. as a
+/// placeholder while the scene is paused or offscreen, without encoding its paths again.
+pub fn snapshot<S: scene::Scene<Renderer> + ?Sized>(
+    scene: &S,
+    viewport: &mut Viewport,
+) -> SceneFragment {
+    let mut renderer = Renderer::default();
+    scene.draw_in(&mut renderer, viewport);
+
+    renderer.into_scene()
+}
+
+/// Poses `linear_animation` at `time` with [`LinearAnimation::apply_at`] and draws it into a
+/// standalone fragment, see [`snapshot`].
+///
+/// The animation's own time is left untouched, so playback continues from where it was on the
+/// next advance.
+pub fn snapshot_at(
+    linear_animation: &mut LinearAnimation<Renderer>,
+    time: Duration,
+    viewport: &mut Viewport,
+) -> SceneFragment {
+    linear_animation.apply_at(time);
+    linear_animation.artboard().advance();
+
+    snapshot(linear_animation, viewport)
+}
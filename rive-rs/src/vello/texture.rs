@@ -0,0 +1,180 @@
+use std::{fmt, sync::mpsc};
+
+use image::RgbaImage;
+use vello::{
+    kurbo::Affine,
+    peniko::Color,
+    wgpu::{
+        self, BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Device, Extent3d,
+        ImageCopyBuffer, ImageDataLayout, Maintain, MapMode, Queue, TextureDescriptor,
+        TextureDimension, TextureFormat, TextureUsages, TextureView, TextureViewDescriptor,
+    },
+    AaConfig, AaSupport, RenderParams, RendererOptions, Scene, SceneBuilder,
+};
+
+use crate::scene::{self, Viewport};
+
+use super::Renderer;
+
+/// Renders scenes into caller-provided wgpu textures, e.g. to use an animation as a dynamic
+/// texture on 3D geometry.
+///
+/// The device and queue are owned by the host. Target textures must have the
+/// `Rgba8Unorm` format and the `STORAGE_BINDING` usage.
+pub struct TextureRenderer {
+    pub(super) renderer: vello::Renderer,
+    rive_renderer: Renderer,
+    scene: Scene,
+}
+
+impl TextureRenderer {
+    pub fn new(device: &Device, queue: &Queue) -> vello::Result<Self> {
+        let renderer = vello::Renderer::new(
+            device,
+            RendererOptions {
+                surface_format: None,
+                timestamp_period: queue.get_timestamp_period(),
+                use_cpu: false,
+                antialiasing_support: AaSupport::area_only(),
+            },
+        )?;
+
+        Ok(Self {
+            renderer,
+            rive_renderer: Renderer::default(),
+            scene: Scene::default(),
+        })
+    }
+
+    /// Draws `scene` fitted into `viewport` and renders it into `texture` over `background`.
+    ///
+    /// `texture` must have the viewport's [`render_size`](Viewport::render_size), which is
+    /// smaller than the viewport itself if it has a [resolution
+    /// scale](Viewport::set_resolution_scale) below `1.0`. The viewport can then be passed on with
+    /// pointer events as usual. The scene is not advanced.
+    pub fn render<S: scene::Scene<Renderer> + ?Sized>(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        scene: &S,
+        viewport: &mut Viewport,
+        texture: &TextureView,
+        background: Color,
+    ) -> vello::Result<()> {
+        self.rive_renderer.reset();
+        scene.draw_in(&mut self.rive_renderer, viewport);
+
+        let (width, height) = viewport.render_size();
+
+        let mut builder = SceneBuilder::for_scene(&mut self.scene);
+        builder.append(self.rive_renderer.scene(), Some(Affine::IDENTITY));
+
+        self.renderer.render_to_texture(
+            device,
+            queue,
+            &self.scene,
+            texture,
+            &RenderParams {
+                base_color: background,
+                width,
+                height,
+                antialiasing_method: AaConfig::Area,
+            },
+        )
+    }
+
+    /// Draws `scene` fitted into `viewport` over `background` and reads the result back into an
+    /// image of the viewport's [`render_size`](Viewport::render_size).
+    ///
+    /// This blocks until the GPU is done, which makes it the simplest way to turn `.riv` files
+    /// into PNGs on a server: share one device between threads and give every worker its own
+    /// `TextureRenderer`, since they are cheap to keep around but not to create. The `server`
+    /// example shows a complete thumbnail service.
+    pub fn render_to_image<S: scene::Scene<Renderer> + ?Sized>(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        scene: &S,
+        viewport: &mut Viewport,
+        background: Color,
+    ) -> vello::Result<RgbaImage> {
+        let (width, height) = viewport.render_size();
+
+        if width == 0 || height == 0 {
+            return Ok(RgbaImage::new(width, height));
+        }
+
+        let size = Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("rive-rs render_to_image"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba8Unorm,
+            usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+
+        self.render(device, queue, scene, viewport, &view, background)?;
+
+        // Rows copied out of textures are padded to a multiple of the alignment, a power of 2.
+        let row_len = width * 4;
+        let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_row_len = (row_len + alignment - 1) & !(alignment - 1);
+
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: Some("rive-rs render_to_image"),
+            size: padded_row_len as u64 * height as u64,
+            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("rive-rs render_to_image"),
+        });
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            ImageCopyBuffer {
+                buffer: &buffer,
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_row_len),
+                    rows_per_image: None,
+                },
+            },
+            size,
+        );
+        queue.submit([encoder.finish()]);
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(Maintain::Wait);
+        receiver.recv()??;
+
+        let mut pixels = Vec::with_capacity(row_len as usize * height as usize);
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(padded_row_len as usize)
+        {
+            pixels.extend_from_slice(&row[..row_len as usize]);
+        }
+        buffer.unmap();
+
+        Ok(RgbaImage::from_raw(width, height, pixels).expect("pixels should match the size"))
+    }
+}
+
+impl fmt::Debug for TextureRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextureRenderer").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,81 @@
+use core::time::Duration;
+
+use image::RgbaImage;
+use vello::{
+    peniko::Color,
+    wgpu::{Device, Queue},
+};
+
+use crate::{
+    artboard::Artboard,
+    file::File,
+    instantiate::{Handle, Instantiate},
+    scene::{Scene, Viewport},
+};
+
+use super::{Renderer, TextureRenderer};
+
+impl File<Renderer> {
+    /// Renders a `width` by `height` thumbnail of the artboard `artboard` over a transparent
+    /// background.
+    ///
+    /// The artboard's default state machine or, if it has none, its first linear animation is
+    /// advanced by `time` first; artboards without either are drawn in their design pose. The
+    /// artboard is fitted into the thumbnail with its default letterbox.
+    ///
+    /// Returns `Ok(None)` if the file has no such artboard.
+    ///
+    /// ```no_run
+    /// # use core::time::Duration;
+    /// # use rive_rs::{vello::TextureRenderer, File, Handle};
+    /// # fn thumbnail(
+    /// #     file: &File,
+    /// #     device: &vello::wgpu::Device,
+    /// #     queue: &vello::wgpu::Queue,
+    /// # ) -> vello::Result<()> {
+    /// let mut renderer = TextureRenderer::new(device, queue)?;
+    ///
+    /// if let Some(image) = file.thumbnail(
+    ///     &mut renderer,
+    ///     device,
+    ///     queue,
+    ///     Handle::Default,
+    ///     (256, 256),
+    ///     Duration::from_millis(500),
+    /// )? {
+    ///     image.save("thumbnail.png")?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn thumbnail(
+        &self,
+        renderer: &mut TextureRenderer,
+        device: &Device,
+        queue: &Queue,
+        artboard: Handle,
+        (width, height): (u32, u32),
+        time: Duration,
+    ) -> vello::Result<Option<RgbaImage>> {
+        let Some(artboard) = Artboard::instantiate(self, artboard) else {
+            return Ok(None);
+        };
+
+        let mut scene = Box::<dyn Scene<Renderer>>::instantiate(&artboard, Handle::Default)
+            .unwrap_or_else(|| Box::new(artboard));
+        scene.advance_and_apply(time);
+
+        let mut viewport = Viewport::default();
+        viewport.resize(width, height);
+
+        renderer
+            .render_to_image(
+                device,
+                queue,
+                scene.as_ref(),
+                &mut viewport,
+                Color::TRANSPARENT,
+            )
+            .map(Some)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::ffi;
+
+/// Version information about the embedded rive-cpp runtime, as returned by [`runtime_version`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RuntimeVersion {
+    /// The rive-cpp commit the crate was built against, or `"unknown"` if it could not be
+    /// determined at build time.
+    pub commit: &'static str,
+    /// The major `.riv` format version supported. Files with a different major version fail to
+    /// load with [`Error::UnsupportedVersion`](crate::Error::UnsupportedVersion).
+    pub major: u32,
+    /// The latest minor `.riv` format version supported. Files with a newer minor version still
+    /// load, but objects and properties unknown to the runtime are skipped.
+    pub minor: u32,
+}
+
+/// Returns the version of the embedded rive-cpp runtime and the `.riv` format version it
+/// supports.
+#[inline]
+pub fn runtime_version() -> RuntimeVersion {
+    RuntimeVersion {
+        commit: ffi::RIVE_CPP_COMMIT,
+        major: unsafe { ffi::rive_rs_file_major_version() },
+        minor: unsafe { ffi::rive_rs_file_minor_version() },
+    }
+}